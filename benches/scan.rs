@@ -0,0 +1,43 @@
+//! Compares scanning unquoted fields with scanning quoted ones, which always take the char-by-char path, and
+//! with a line of a single field, which needs no scan at all. Unquoted fields are also split by
+//! `CsvRow::unquoted`, which searches for each delimiter directly. Each is also scanned by CsvRowConst, whose delimiter
+//! is a compile-time constant.
+//!
+//! Run with `cargo bench` and again with `cargo bench --features memchr` to compare the two unquoted paths.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use csvrow::{CsvRow, CsvRowConst};
+
+fn scan(c: &mut Criterion) {
+    let unquoted = (0..1_000)
+        .map(|i| format!("field number {i}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let quoted = (0..1_000)
+        .map(|i| format!("\"field, number {i}\""))
+        .collect::<Vec<_>>()
+        .join(",");
+    let single = "single field ".repeat(1_000);
+
+    c.bench_function("unquoted fields", |b| {
+        b.iter(|| CsvRow::new(black_box(&unquoted), ',', true).count())
+    });
+    c.bench_function("unquoted fields, quoting off", |b| {
+        b.iter(|| CsvRow::unquoted(black_box(&unquoted), ',').count())
+    });
+    c.bench_function("quoted fields", |b| {
+        b.iter(|| CsvRow::new(black_box(&quoted), ',', true).count())
+    });
+    c.bench_function("single field line", |b| {
+        b.iter(|| CsvRow::new(black_box(&single), ',', true).count())
+    });
+    c.bench_function("unquoted fields, const delimiter", |b| {
+        b.iter(|| CsvRowConst::<','>::new(black_box(&unquoted), true).count())
+    });
+    c.bench_function("quoted fields, const delimiter", |b| {
+        b.iter(|| CsvRowConst::<','>::new(black_box(&quoted), true).count())
+    });
+}
+
+criterion_group!(benches, scan);
+criterion_main!(benches);
@@ -0,0 +1,141 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use futures_core::Stream;
+use futures_io::AsyncRead;
+
+use crate::reader::{RecordBuffer, DEFAULT_CAPACITY};
+
+/// A stream of the records read from an asynchronous source of UTF-8 text, such as a socket.
+///
+/// Records are buffered and split into fields exactly as with `CsvReader`, so a quoted field may span
+/// several reads.
+pub struct AsyncCsvReader<R> {
+    reader: R,
+    pub delimiter: char,
+    pub literal: bool,
+    records: RecordBuffer,
+}
+
+impl<R: AsyncRead + Unpin> AsyncCsvReader<R> {
+    /// Creates a new AsyncCsvReader
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A source that holds the records, separated by newlines
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::AsyncCsvReader;
+    /// use futures::executor::block_on;
+    /// use futures::io::Cursor;
+    /// use futures::TryStreamExt;
+    ///
+    /// let source = Cursor::new("a,\"b\nc\"\nd,e".as_bytes());
+    /// let reader = AsyncCsvReader::new(source, ',', false);
+    /// let vec_r: Vec<_> = block_on(reader.try_collect::<Vec<_>>()).unwrap();
+    ///
+    /// assert_eq!(vec_r, vec![vec!["a", "b\nc"], vec!["d", "e"]])
+    /// ```
+    pub fn new(reader: R, delimiter: char, literal: bool) -> AsyncCsvReader<R> {
+        AsyncCsvReader::with_capacity(DEFAULT_CAPACITY, reader, delimiter, literal)
+    }
+
+    /// Creates a new AsyncCsvReader that requests `capacity` bytes from `reader` at a time
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of bytes to read at a time
+    /// * `reader` - A source that holds the records, separated by newlines
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(
+        capacity: usize,
+        reader: R,
+        delimiter: char,
+        literal: bool,
+    ) -> AsyncCsvReader<R> {
+        AsyncCsvReader {
+            reader,
+            delimiter,
+            literal,
+            records: RecordBuffer::new(capacity),
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncCsvReader<R> {
+    type Item = io::Result<Vec<String>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(next) = this.records.next_record(this.delimiter, this.literal) {
+                return Poll::Ready(next);
+            }
+
+            let read = Pin::new(&mut this.reader).poll_read(cx, this.records.unfilled());
+
+            match read {
+                Poll::Ready(Ok(read)) => this.records.filled(Some(read)),
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => {
+                    this.records.filled(None)
+                }
+                Poll::Ready(Err(e)) => {
+                    this.records.filled(None);
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Pending => {
+                    this.records.filled(None);
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use futures::executor::block_on;
+    use futures::io::Cursor;
+    use futures::TryStreamExt;
+
+    #[test]
+    fn can_stream_records_with_newline_straddling_reads() {
+        let source = Cursor::new("january,\"leap\nday\",march\napril,may\n".as_bytes());
+
+        let reader = AsyncCsvReader::with_capacity(4, source, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["january", "leap\nday", "march"], vec!["april", "may"]];
+        let vec_r: Vec<_> = block_on(reader.try_collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_error() {
+        let source = Cursor::new(b"a,b\n\xFF,c\n".to_vec());
+
+        let mut reader = AsyncCsvReader::new(source, ',', false);
+
+        assert_eq!(
+            block_on(reader.try_next()).unwrap(),
+            Some(vec!["a".into(), "b".into()])
+        );
+        assert_eq!(
+            block_on(reader.try_next()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
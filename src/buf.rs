@@ -0,0 +1,77 @@
+use alloc::string::String;
+
+use crate::CsvRow;
+
+/// A line that owns its text, for parsing once the source it was read from is gone
+///
+/// Each call to `iter` returns a CsvRow that borrows the line, so fields can be parsed as often as needed without
+/// tying the line to the lifetime of a buffer.
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::CsvRowBuf;
+/// let rows: Vec<_> = ["a,b", "\"c,d\",e"]
+///     .iter()
+///     .map(|line| CsvRowBuf::new(line.to_string(), ',', false))
+///     .collect();
+/// let vec_r: Vec<Vec<_>> = rows.iter().map(|row| row.iter().collect()).collect();
+///
+/// assert_eq!(vec_r, vec![vec!["a", "b"], vec!["c,d", "e"]])
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvRowBuf {
+    pub line: String,
+    pub delimiter: char,
+    pub literal: bool,
+}
+
+impl CsvRowBuf {
+    /// Creates a new CsvRowBuf
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A String that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    pub fn new(line: String, delimiter: char, literal: bool) -> CsvRowBuf {
+        CsvRowBuf {
+            line,
+            delimiter,
+            literal,
+        }
+    }
+
+    /// Returns a CsvRow over the fields of the line, as `CsvRow::new` would create it
+    pub fn iter(&self) -> CsvRow<'_> {
+        CsvRow::new(&self.line, self.delimiter, self.literal)
+    }
+}
+
+impl<'a> IntoIterator for &'a CsvRowBuf {
+    type Item = <CsvRow<'a> as Iterator>::Item;
+    type IntoIter = CsvRow<'a>;
+
+    fn into_iter(self) -> CsvRow<'a> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn iterates_like_borrowed_row() {
+        let line = r#"january,"The ""Coder"" Man",,"leap, day""#;
+
+        let row = CsvRowBuf::new(String::from(line), ',', false);
+
+        let vec_t: Vec<_> = CsvRow::new(line, ',', false).collect();
+        let vec_r: Vec<_> = row.iter().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_r, (&row).into_iter().collect::<Vec<_>>());
+    }
+}
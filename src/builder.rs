@@ -0,0 +1,287 @@
+use crate::{CsvRow, EmptyLine, EscapeStyle, FieldMode, QuoteMode, TrimMode};
+
+/// A reusable parser configuration that creates a CsvRow for each line
+///
+/// Every option defaults to the value `CsvRow::new` uses, and each is applied with the CsvRow setter of the same
+/// name.
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::CsvRowBuilder;
+/// let builder = CsvRowBuilder::new(',').quote('\'').trim(true);
+/// let vec_t: Vec<_> = vec!["a", "b,c", "d"];
+/// let vec_r: Vec<_> = builder.build("a, 'b,c' ,d").collect();
+///
+/// assert_eq!(vec_t[..], vec_r[..])
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsvRowBuilder<'c> {
+    delimiter: char,
+    mode: FieldMode,
+    quote: char,
+    escape_style: EscapeStyle,
+    trim_mode: TrimMode,
+    trim_chars: Option<&'c [char]>,
+    skip_space_before_quote: bool,
+    strict: bool,
+    strip_bom: bool,
+    trim_cr: bool,
+    collapse_delimiters: bool,
+    max_field_len: Option<usize>,
+    reject_nul: bool,
+    empty_line: EmptyLine,
+    quote_mode: QuoteMode,
+}
+
+impl<'c> CsvRowBuilder<'c> {
+    /// Creates a new CsvRowBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - A char that represents the delimiter
+    pub fn new(delimiter: char) -> CsvRowBuilder<'c> {
+        CsvRowBuilder {
+            delimiter,
+            mode: FieldMode::Unescaped,
+            quote: '"',
+            escape_style: EscapeStyle::Doubled,
+            trim_mode: TrimMode::Off,
+            trim_chars: None,
+            skip_space_before_quote: false,
+            strict: false,
+            strip_bom: false,
+            trim_cr: false,
+            collapse_delimiters: false,
+            max_field_len: None,
+            reject_nul: false,
+            empty_line: EmptyLine::NoFields,
+            quote_mode: QuoteMode::Standard,
+        }
+    }
+
+    /// Sets whether fields are read literally, as the `literal` argument of `CsvRow::new` does
+    pub fn literal(mut self, literal: bool) -> CsvRowBuilder<'c> {
+        self.mode = literal.into();
+        self
+    }
+
+    /// See `CsvRow::mode`
+    pub fn mode(mut self, mode: FieldMode) -> CsvRowBuilder<'c> {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the quote used to enclose fields, as the `quote` argument of `CsvRow::with_quote` does
+    pub fn quote(mut self, quote: char) -> CsvRowBuilder<'c> {
+        self.quote = quote;
+        self
+    }
+
+    /// See `CsvRow::escape_style`
+    pub fn escape_style(mut self, escape_style: EscapeStyle) -> CsvRowBuilder<'c> {
+        self.escape_style = escape_style;
+        self
+    }
+
+    /// See `CsvRow::trim`
+    pub fn trim(mut self, trim: bool) -> CsvRowBuilder<'c> {
+        self.trim_mode = match trim {
+            true => TrimMode::Unquoted,
+            false => TrimMode::Off,
+        };
+        self
+    }
+
+    /// See `CsvRow::trim_mode`
+    pub fn trim_mode(mut self, trim_mode: TrimMode) -> CsvRowBuilder<'c> {
+        self.trim_mode = trim_mode;
+        self
+    }
+
+    /// See `CsvRow::trim_chars`
+    ///
+    /// The builder borrows `trim_chars`, so each CsvRow it builds lives no longer than the slice.
+    pub fn trim_chars(mut self, trim_chars: &'c [char]) -> CsvRowBuilder<'c> {
+        self.trim_chars = Some(trim_chars);
+
+        if self.trim_mode == TrimMode::Off {
+            self.trim_mode = TrimMode::Unquoted;
+        }
+        self
+    }
+
+    /// See `CsvRow::skip_space_before_quote`
+    pub fn skip_space_before_quote(mut self, skip_space_before_quote: bool) -> CsvRowBuilder<'c> {
+        self.skip_space_before_quote = skip_space_before_quote;
+        self
+    }
+
+    /// See `CsvRow::strict`
+    pub fn strict(mut self, strict: bool) -> CsvRowBuilder<'c> {
+        self.strict = strict;
+        self
+    }
+
+    /// See `CsvRow::strip_bom`
+    pub fn strip_bom(mut self, strip_bom: bool) -> CsvRowBuilder<'c> {
+        self.strip_bom = strip_bom;
+        self
+    }
+
+    /// See `CsvRow::trim_cr`
+    pub fn trim_cr(mut self, trim_cr: bool) -> CsvRowBuilder<'c> {
+        self.trim_cr = trim_cr;
+        self
+    }
+
+    /// See `CsvRow::collapse_delimiters`
+    pub fn collapse_delimiters(mut self, collapse_delimiters: bool) -> CsvRowBuilder<'c> {
+        self.collapse_delimiters = collapse_delimiters;
+        self
+    }
+
+    /// See `CsvRow::max_field_len`
+    pub fn max_field_len(mut self, max_field_len: Option<usize>) -> CsvRowBuilder<'c> {
+        self.max_field_len = max_field_len;
+        self
+    }
+
+    /// See `CsvRow::reject_nul`
+    pub fn reject_nul(mut self, reject_nul: bool) -> CsvRowBuilder<'c> {
+        self.reject_nul = reject_nul;
+        self
+    }
+
+    /// See `CsvRow::empty_line_yields`
+    pub fn empty_line_yields(mut self, empty_line: EmptyLine) -> CsvRowBuilder<'c> {
+        self.empty_line = empty_line;
+        self
+    }
+
+    /// See `CsvRow::quote_mode`
+    pub fn quote_mode(mut self, quote_mode: QuoteMode) -> CsvRowBuilder<'c> {
+        self.quote_mode = quote_mode;
+        self
+    }
+
+    /// Creates a CsvRow over `line` with this configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    pub fn build<'a>(&self, line: &'a str) -> CsvRow<'a>
+    where
+        'c: 'a,
+    {
+        let row = CsvRow::with_quote(line, self.delimiter, false, self.quote).mode(self.mode);
+        let row = match self.trim_chars {
+            Some(trim_chars) => row.trim_chars(trim_chars),
+            None => row,
+        };
+
+        row.escape_style(self.escape_style)
+            .trim_mode(self.trim_mode)
+            .skip_space_before_quote(self.skip_space_before_quote)
+            .strict(self.strict)
+            .strip_bom(self.strip_bom)
+            .trim_cr(self.trim_cr)
+            .collapse_delimiters(self.collapse_delimiters)
+            .max_field_len(self.max_field_len)
+            .reject_nul(self.reject_nul)
+            .empty_line_yields(self.empty_line)
+            .quote_mode(self.quote_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::CsvError;
+
+    #[test]
+    fn builds_default_row() {
+        let row = r#"a;"b;c";d"#;
+
+        let csv = CsvRowBuilder::new(';').build(row);
+        let vec_t: Vec<_> = vec!["a", "b;c", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn builds_row_with_quote_and_literal() {
+        let row = "a,'b,c'";
+
+        let csv = CsvRowBuilder::new(',').quote('\'').literal(true).build(row);
+        let vec_t: Vec<_> = vec!["a", "'b,c'"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn builds_row_with_escape_style_and_trim() {
+        let row = r#" "a\"b" , c "#;
+
+        let csv = CsvRowBuilder::new(',')
+            .escape_style(EscapeStyle::Backslash)
+            .trim(true)
+            .build(row);
+        let vec_t: Vec<_> = vec!["a\"b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn builds_row_with_borrowed_trim_chars() {
+        let trim_chars: Vec<char> = "*~".chars().collect();
+        let builder = CsvRowBuilder::new(',').trim_chars(&trim_chars);
+
+        let vec_t: Vec<_> = vec!["a", "b*"];
+        let vec_r: Vec<_> = builder.build(r#"*a~,~"b*"*"#).collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn builds_row_with_skip_space_before_quote() {
+        let row = r#"a, "b,c""#;
+
+        let csv = CsvRowBuilder::new(',')
+            .skip_space_before_quote(true)
+            .build(row);
+        let vec_t: Vec<_> = vec!["a", "b,c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn builds_strict_row() {
+        let row = r#"a,b"c"#;
+
+        let mut csv = CsvRowBuilder::new(',').strict(true).build(row).try_fields();
+        csv.next();
+
+        assert_eq!(
+            Some(Err(CsvError::MalformedField { byte_pos: 3 })),
+            csv.next()
+        )
+    }
+
+    #[test]
+    fn builds_row_with_bom_and_cr_removed() {
+        let builder = CsvRowBuilder::new(',').strip_bom(true).trim_cr(true);
+
+        for row in ["\u{FEFF}a,b\r", "a,b"] {
+            let vec_t: Vec<_> = vec!["a", "b"];
+            let vec_r: Vec<_> = builder.build(row).collect();
+
+            assert_eq!(vec_t[..], vec_r[..])
+        }
+    }
+}
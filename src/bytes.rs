@@ -0,0 +1,261 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// An iterator over the fields of a line that need not be valid UTF-8, such as Latin-1 exports.
+///
+/// Fields are quoted with `"` and escaped by doubling, exactly as with `CsvRow`.
+pub struct CsvRowBytes<'a> {
+    pub line: &'a [u8],
+    pub delimiter: u8,
+    pub literal: bool,
+    byte_pos: usize,
+}
+
+impl<'a> CsvRowBytes<'a> {
+    /// Creates a new CsvRowBytes
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A byte slice that holds the delimited fields
+    /// * `delimiter` - A byte that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRowBytes;
+    /// let row = b"a,\"b\xFF\",c";
+    /// let csv = CsvRowBytes::new(row, b',', false);
+    /// let vec_t: Vec<&[u8]> = vec![b"a", b"b\xFF", b"c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_r[..], vec_t[..])
+    /// ```
+    pub fn new(line: &'a [u8], delimiter: u8, literal: bool) -> CsvRowBytes<'a> {
+        CsvRowBytes {
+            line,
+            delimiter,
+            literal,
+            byte_pos: 0,
+        }
+    }
+
+    /// Returns an iterator that decodes each remaining field as UTF-8, replacing invalid bytes with `U+FFFD`
+    ///
+    /// As with `String::from_utf8_lossy`, a field that is valid UTF-8 is borrowed from `line` unless it had to be
+    /// unescaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRowBytes;
+    /// let row = b"caf\xC3\xA9,\"b\xFFd\"";
+    /// let vec_r: Vec<_> = CsvRowBytes::new(row, b',', false).to_string_lossy_fields().collect();
+    ///
+    /// assert_eq!(vec!["café", "b\u{FFFD}d"], vec_r)
+    /// ```
+    pub fn to_string_lossy_fields(self) -> LossyFields<'a> {
+        LossyFields { row: self }
+    }
+}
+
+impl<'a> Iterator for CsvRowBytes<'a> {
+    type Item = Cow<'a, [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.byte_pos > self.line.len() || self.line.is_empty() {
+            return None;
+        }
+
+        let rest = &self.line[self.byte_pos..];
+        let quoted = rest.first() == Some(&b'"');
+        let mut byte_length: usize = 0;
+        // Within a quoted field, whether the last quote opened rather than closed the quotes
+        let mut in_quotes = false;
+        let mut prev_byte = None;
+
+        for &b in rest {
+            if b == self.delimiter && (!quoted || !in_quotes && prev_byte == Some(b'"')) {
+                break;
+            }
+
+            if quoted && b == b'"' {
+                in_quotes = byte_length == 0 || !in_quotes;
+            }
+
+            byte_length += 1;
+            prev_byte = Some(b);
+        }
+
+        // A lone quote that is never closed is a field of its own, as with CsvRow
+        if quoted && in_quotes && rest.get(1) == Some(&self.delimiter) {
+            byte_length = 1;
+        }
+
+        let mut result = &rest[..byte_length];
+        self.byte_pos += byte_length + 1;
+
+        if self.literal {
+            return Some(Cow::Borrowed(result));
+        }
+
+        // If the field is in quotes, trim them off
+        if quoted && result.len() > 1 && result.ends_with(b"\"") {
+            result = &result[1..result.len() - 1];
+        }
+
+        Some(collapse_quotes(result))
+    }
+}
+
+/// An iterator over the fields of a CsvRowBytes that decodes each one as UTF-8, replacing invalid bytes
+///
+/// Created by `CsvRowBytes::to_string_lossy_fields`.
+pub struct LossyFields<'a> {
+    row: CsvRowBytes<'a>,
+}
+
+impl<'a> Iterator for LossyFields<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(match self.row.next()? {
+            Cow::Borrowed(field) => String::from_utf8_lossy(field),
+            Cow::Owned(field) => match String::from_utf8(field) {
+                Ok(field) => Cow::Owned(field),
+                Err(e) => Cow::Owned(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+            },
+        })
+    }
+}
+
+impl FusedIterator for LossyFields<'_> {}
+
+/// Collapses each doubled `"` in `field` into a single `"`.
+fn collapse_quotes(field: &[u8]) -> Cow<'_, [u8]> {
+    if !field.windows(2).any(|w| w == b"\"\"") {
+        return Cow::Borrowed(field);
+    }
+
+    let mut unescaped = Vec::with_capacity(field.len());
+    let mut bytes = field.iter().peekable();
+
+    while let Some(&b) = bytes.next() {
+        unescaped.push(b);
+
+        if b == b'"' {
+            bytes.next_if(|&&n| n == b'"');
+        }
+    }
+
+    Cow::Owned(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn can_parse_tiny_csv_bytes() {
+        let row = b"a,b,c,d";
+
+        let csv = CsvRowBytes::new(row, b',', false);
+
+        let vec_t: Vec<&[u8]> = vec![b"a", b"b", b"c", b"d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+
+    #[test]
+    fn can_parse_empty_row_bytes() {
+        let csv = CsvRowBytes::new(b"", b',', false);
+
+        assert_eq!(csv.count(), 0)
+    }
+
+    #[test]
+    fn trailing_comma_yields_empty_bytes() {
+        let row = b"january,";
+
+        let csv = CsvRowBytes::new(row, b',', false);
+
+        let vec_t: Vec<&[u8]> = vec![b"january", b""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+
+    #[test]
+    fn can_parse_invalid_utf8_in_quoted_field() {
+        let row = b"caf\xE9,\"\xFF, \"\"\xFF\"\"\",end";
+
+        let csv = CsvRowBytes::new(row, b',', false);
+
+        let vec_t: Vec<&[u8]> = vec![b"caf\xE9", b"\xFF, \"\xFF\"", b"end"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+
+    #[test]
+    fn can_parse_literal_invalid_utf8_in_quoted_field() {
+        let row = b"a,\"\xFF,\"\"b\"";
+
+        let csv = CsvRowBytes::new(row, b',', true);
+
+        let vec_t: Vec<&[u8]> = vec![b"a", b"\"\xFF,\"\"b\""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+
+    #[test]
+    fn can_parse_bytes_with_premature_close_quote() {
+        let row = b"january,\"feb\"ruary,march";
+
+        let csv = CsvRowBytes::new(row, b',', false);
+
+        let vec_t: Vec<&[u8]> = vec![b"january", b"\"feb\"ruary,march"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+
+    #[test]
+    fn can_parse_trailing_quoted_delimiter_bytes() {
+        let row = b"\"a\xFF\",\",\"";
+
+        let csv = CsvRowBytes::new(row, b',', false);
+        let vec_t: Vec<&[u8]> = vec![b"a\xFF", b","];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+
+    #[test]
+    fn lossy_fields_replace_invalid_bytes() {
+        let row = b"ok,caf\xE9,\"a \"\"\xFF\"\"\"";
+
+        let csv = CsvRowBytes::new(row, b',', false).to_string_lossy_fields();
+
+        let vec_t: Vec<_> = vec!["ok", "caf\u{FFFD}", "a \"\u{FFFD}\""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn lone_quote_is_a_field_of_its_own_bytes() {
+        let row = b"a,\",b\xFF";
+
+        let csv = CsvRowBytes::new(row, b',', false);
+        let vec_t: Vec<&[u8]> = vec![b"a", b"\"", b"b\xFF"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_r[..], vec_t[..])
+    }
+}
@@ -0,0 +1,292 @@
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::fmt::Display;
+use core::iter::Peekable;
+use core::str::FromStr;
+
+use serde::de::value::CowStrDeserializer;
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, SeqAccess, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+use crate::{CsvError, CsvRow};
+
+/// Deserializes the fields of `line` into a `T`, such as a struct or tuple, by position.
+///
+/// Each field is unescaped as it would be by `CsvRow::new(line, delimiter, false)` before it is parsed.
+/// An empty field deserializes as `None` into an `Option`. Requires the `serde` feature.
+///
+/// # Arguments
+///
+/// * `line` - A string slice that holds the delimited fields
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Record {
+///     id: u32,
+///     name: String,
+///     weight: Option<f64>,
+/// }
+///
+/// let record: Record = csvrow::from_row("1,\"Smith, J\",", ',').unwrap();
+///
+/// assert_eq!(record.id, 1);
+/// assert_eq!(record.name, "Smith, J");
+/// assert_eq!(record.weight, None);
+/// ```
+pub fn from_row<'de, T: Deserialize<'de>>(line: &'de str, delimiter: char) -> Result<T, CsvError> {
+    let mut deserializer = RowDeserializer {
+        fields: CsvRow::new(line, delimiter, false).peekable(),
+        index: 0,
+    };
+
+    let value = T::deserialize(&mut deserializer)?;
+
+    match deserializer.fields.peek() {
+        None => Ok(value),
+        Some(_) => Err(de::Error::custom(format_args!(
+            "row has more than the expected {} fields",
+            deserializer.index
+        ))),
+    }
+}
+
+impl de::Error for CsvError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CsvError::Deserialize(msg.to_string())
+    }
+}
+
+/// Deserializes a whole row as a sequence of its fields
+struct RowDeserializer<'de> {
+    fields: Peekable<CsvRow<'de>>,
+    index: usize,
+}
+
+impl<'de> Deserializer<'de> for &mut RowDeserializer<'de> {
+    type Error = CsvError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_seq(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> SeqAccess<'de> for RowDeserializer<'de> {
+    type Error = CsvError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, CsvError> {
+        let Some(value) = self.fields.next() else {
+            return Ok(None);
+        };
+
+        let field = FieldDeserializer {
+            value,
+            index: self.index,
+        };
+        self.index += 1;
+
+        seed.deserialize(field).map(Some)
+    }
+}
+
+/// Deserializes a single unescaped field
+struct FieldDeserializer<'de> {
+    value: Cow<'de, str>,
+    index: usize,
+}
+
+impl FieldDeserializer<'_> {
+    fn parse<T: FromStr>(&self) -> Result<T, CsvError>
+    where
+        T::Err: Display,
+    {
+        self.value.parse().map_err(|e| {
+            de::Error::custom(format_args!(
+                "field {}: cannot parse {:?}: {e}",
+                self.index, self.value
+            ))
+        })
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FieldDeserializer<'de> {
+    type Error = CsvError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        match self.value {
+            Cow::Borrowed(value) => visitor.visit_borrowed_str(value),
+            Cow::Owned(value) => visitor.visit_string(value),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        match self.value.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        match self.value.is_empty() {
+            true => visitor.visit_unit(),
+            false => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CsvError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CsvError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CsvError> {
+        let variant: CowStrDeserializer<'de, CsvError> = self.value.into_deserializer();
+
+        visitor.visit_enum(variant)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf seq tuple tuple_struct map struct identifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        id: u32,
+        name: String,
+        weight: f64,
+    }
+
+    #[test]
+    fn can_deserialize_struct() {
+        let row = "1,hello,3.5";
+
+        let record: Record = from_row(row, ',').unwrap();
+
+        assert_eq!(
+            Record {
+                id: 1,
+                name: "hello".into(),
+                weight: 3.5
+            },
+            record
+        )
+    }
+
+    #[test]
+    fn can_deserialize_escaped_and_borrowed_fields() {
+        let row = r#"plain,"The ""Coder"" Man",,b"#;
+
+        let record: (&str, String, Option<u32>, char) = from_row(row, ',').unwrap();
+
+        assert_eq!(("plain", "The \"Coder\" Man".into(), None, 'b'), record)
+    }
+
+    #[test]
+    fn can_deserialize_unit_enum() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        enum Month {
+            January,
+            February,
+        }
+
+        let months: Vec<Month> = from_row("February;January", ';').unwrap();
+
+        assert_eq!(vec![Month::February, Month::January], months)
+    }
+
+    #[test]
+    fn unparseable_field_is_an_error() {
+        let result: Result<Record, _> = from_row("1,hello,heavy", ',');
+
+        assert_eq!(
+            Err(CsvError::Deserialize(
+                "field 2: cannot parse \"heavy\": invalid float literal".into()
+            )),
+            result
+        )
+    }
+
+    #[test]
+    fn missing_field_is_an_error() {
+        let result: Result<Record, _> = from_row("1,hello", ',');
+
+        assert!(matches!(result, Err(CsvError::Deserialize(_))))
+    }
+
+    #[test]
+    fn extra_field_is_an_error() {
+        let result: Result<Record, _> = from_row("1,hello,3.5,extra", ',');
+
+        assert_eq!(
+            Err(CsvError::Deserialize(
+                "row has more than the expected 3 fields".into()
+            )),
+            result
+        )
+    }
+}
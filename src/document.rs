@@ -0,0 +1,790 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{CsvError, CsvRow};
+
+/// An iterator over the records of a CSV document, where each record is a `Vec` of its fields.
+///
+/// Records are separated by `\n` or `\r\n`, or by another terminator set with `terminator`, except within a
+/// quoted field, so a field may span several lines.
+pub struct CsvDocument<'a> {
+    pub text: &'a str,
+    pub delimiter: char,
+    pub literal: bool,
+    terminator: char,
+    comment: Option<char>,
+    skip_rows: usize,
+    byte_pos: usize,
+}
+
+impl<'a> CsvDocument<'a> {
+    /// Creates a new CsvDocument
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the records, separated by newlines
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "a,\"b\nc\"\nd,e";
+    /// let doc = CsvDocument::new(text, ',', false);
+    /// let vec_r: Vec<_> = doc.collect();
+    ///
+    /// assert_eq!(vec_r, vec![vec!["a", "b\nc"], vec!["d", "e"]])
+    /// ```
+    pub fn new(text: &'a str, delimiter: char, literal: bool) -> CsvDocument<'a> {
+        CsvDocument {
+            text,
+            delimiter,
+            literal,
+            terminator: '\n',
+            comment: None,
+            skip_rows: 0,
+            byte_pos: 0,
+        }
+    }
+
+    /// Sets the char that ends each record, instead of a newline
+    ///
+    /// A terminator within a quoted field is part of the field. The default `\n` terminator also matches a CRLF, so
+    /// the `\r` is never left at the end of the last field; any other terminator must match exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminator` - A char that represents the end of a record
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "a,\"b\u{1E}c\"\u{1E}d,e";
+    /// let doc = CsvDocument::new(text, ',', false).terminator('\u{1E}');
+    /// let vec_r: Vec<_> = doc.collect();
+    ///
+    /// assert_eq!(vec_r, vec![vec!["a", "b\u{1E}c"], vec!["d", "e"]])
+    /// ```
+    pub fn terminator(mut self, terminator: char) -> CsvDocument<'a> {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Sets a char that marks a line as a comment to skip
+    ///
+    /// A line is a comment if its first non-whitespace char is `comment`. This is only checked at the start
+    /// of a record, never within a quoted field that spans several lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - A char that represents the start of a comment
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "# exported 2024-01-01\na,b,c";
+    /// let doc = CsvDocument::new(text, ',', false).comment('#');
+    /// let vec_r: Vec<_> = doc.collect();
+    ///
+    /// assert_eq!(vec_r, vec![vec!["a", "b", "c"]])
+    /// ```
+    pub fn comment(mut self, comment: char) -> CsvDocument<'a> {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Sets a number of leading records to discard, such as title rows before the header
+    ///
+    /// Records are skipped as they would be parsed, so a quoted field spanning several lines is one record. Comment
+    /// lines are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_rows` - A usize that represents the number of records to discard
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "Monthly report\n\"exported\n2024-01-01\"\nmonth,days\njanuary,31";
+    /// let mut doc = CsvDocument::new(text, ',', false).skip_rows(2);
+    ///
+    /// assert_eq!(Some(vec!["month".into(), "days".into()]), doc.next())
+    /// ```
+    pub fn skip_rows(mut self, skip_rows: usize) -> CsvDocument<'a> {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Checks that every record has as many fields as the first, counting fields within quotes correctly
+    ///
+    /// Returns the line number at which the first mismatched record starts, counting from 1, along with the
+    /// expected and found field counts. A blank line is a record without any fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "a,b\n\"c,d\",e\nf";
+    ///
+    /// assert_eq!(Err((3, 2, 1)), CsvDocument::new(text, ',', false).check_field_counts())
+    /// ```
+    pub fn check_field_counts(mut self) -> Result<(), (usize, usize, usize)> {
+        let mut expected = None;
+        let mut line = 1;
+        let mut counted = self.byte_pos;
+        self.skip_preamble();
+
+        loop {
+            let rest = self.skip_comments();
+
+            if rest.is_empty() {
+                return Ok(());
+            }
+
+            line += self.text[counted..self.byte_pos]
+                .matches(self.terminator)
+                .count();
+            counted = self.byte_pos;
+
+            let (record, length, _) = parse_record(rest, self.delimiter, self.terminator, true);
+            self.byte_pos += length;
+
+            match expected {
+                None => expected = Some(record.len()),
+                Some(expected) if expected != record.len() => {
+                    return Err((line, expected, record.len()))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Moves past the leading records still to be skipped
+    fn skip_preamble(&mut self) {
+        while self.skip_rows > 0 {
+            let rest = self.skip_comments();
+
+            if rest.is_empty() {
+                return;
+            }
+
+            self.byte_pos += record_len(rest, self.delimiter, self.terminator);
+            self.skip_rows -= 1;
+        }
+    }
+
+    /// Moves past any comment lines at the current position, returning the text that follows them
+    fn skip_comments(&mut self) -> &'a str {
+        let mut rest = &self.text[self.byte_pos.min(self.text.len())..];
+
+        while let Some(comment) = self.comment {
+            if !rest
+                .trim_start_matches(|c: char| c.is_whitespace() && c != self.terminator)
+                .starts_with(comment)
+            {
+                break;
+            }
+
+            let length = rest
+                .find(self.terminator)
+                .map_or(rest.len(), |i| i + self.terminator.len_utf8());
+            self.byte_pos += length;
+            rest = &rest[length..];
+        }
+
+        rest
+    }
+
+    /// Reads the first record as a header, returning an iterator over the remaining records keyed by header name
+    ///
+    /// A record with fewer fields than the header maps the missing names to empty fields, while a record with more
+    /// fields is reported as `CsvError::TooManyFields`. If two headers share a name, the field of the first is kept,
+    /// as with `RecordView::get`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "name,city\nAda,\"London, UK\"";
+    /// let mut records = CsvDocument::new(text, ',', false).with_headers();
+    /// let record = records.next().unwrap().unwrap();
+    ///
+    /// assert_eq!("London, UK", record["city"])
+    /// ```
+    pub fn with_headers(mut self) -> NamedRecords<'a> {
+        let headers = self.next().unwrap_or_default();
+
+        NamedRecords {
+            document: self,
+            headers,
+        }
+    }
+
+    /// Reads the first record as a header, returning an iterator that builds a value from each remaining record
+    ///
+    /// `f` receives each record as a RecordView, which derefs to the slice of its fields and looks them up by header
+    /// name, so typed records can be built without serde.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure that builds a value from a record
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvDocument;
+    /// let text = "name,city\nAda,\"London, UK\"";
+    /// let cities: Vec<_> = CsvDocument::new(text, ',', false)
+    ///     .records_as(|record| record.get("city").unwrap_or_default().to_uppercase())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["LONDON, UK"], cities)
+    /// ```
+    pub fn records_as<T, F>(mut self, f: F) -> RecordsAs<'a, F>
+    where
+        F: FnMut(RecordView<'_, 'a>) -> T,
+    {
+        let headers = self.next().unwrap_or_default();
+
+        RecordsAs {
+            document: self,
+            headers,
+            f,
+        }
+    }
+}
+
+impl<'a> Iterator for CsvDocument<'a> {
+    type Item = Vec<Cow<'a, str>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_preamble();
+        let rest = self.skip_comments();
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let (record, length, _) = parse_record(rest, self.delimiter, self.terminator, self.literal);
+        self.byte_pos += length;
+
+        Some(record)
+    }
+}
+
+/// An iterator over the records of a CSV document that maps each field to the name of its column
+///
+/// Created by `CsvDocument::with_headers`.
+pub struct NamedRecords<'a> {
+    document: CsvDocument<'a>,
+    headers: Vec<Cow<'a, str>>,
+}
+
+impl<'a> NamedRecords<'a> {
+    /// Returns the column names read from the first record
+    pub fn headers(&self) -> &[Cow<'a, str>] {
+        &self.headers
+    }
+}
+
+impl<'a> Iterator for NamedRecords<'a> {
+    type Item = Result<BTreeMap<Cow<'a, str>, Cow<'a, str>>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.document.next()?;
+
+        if record.len() > self.headers.len() {
+            return Some(Err(CsvError::TooManyFields {
+                expected: self.headers.len(),
+                found: record.len(),
+            }));
+        }
+
+        let mut fields = record.into_iter();
+        let mut named = BTreeMap::new();
+
+        for header in &self.headers {
+            let field = fields.next().unwrap_or_default();
+            named.entry(header.clone()).or_insert(field);
+        }
+
+        Some(Ok(named))
+    }
+}
+
+/// An iterator over the records of a CSV document that builds a value from each one with a closure
+///
+/// Created by `CsvDocument::records_as`.
+pub struct RecordsAs<'a, F> {
+    document: CsvDocument<'a>,
+    headers: Vec<Cow<'a, str>>,
+    f: F,
+}
+
+impl<'a, F> RecordsAs<'a, F> {
+    /// Returns the column names read from the first record
+    pub fn headers(&self) -> &[Cow<'a, str>] {
+        &self.headers
+    }
+}
+
+impl<'a, T, F: FnMut(RecordView<'_, 'a>) -> T> Iterator for RecordsAs<'a, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let fields = self.document.next()?;
+
+        Some((self.f)(RecordView {
+            headers: &self.headers,
+            fields: &fields,
+        }))
+    }
+}
+
+/// The fields of a record along with the header names of its columns
+///
+/// Derefs to the slice of the fields. Passed to the closure of `CsvDocument::records_as`.
+#[derive(Clone, Copy, Debug)]
+pub struct RecordView<'r, 'a> {
+    headers: &'r [Cow<'a, str>],
+    fields: &'r [Cow<'a, str>],
+}
+
+impl<'r> RecordView<'r, '_> {
+    /// Returns the field in the column named `name`, or None if there is no such column or the record is too short
+    ///
+    /// If two headers share a name, the field of the first is returned.
+    pub fn get(&self, name: &str) -> Option<&'r str> {
+        let i = self.headers.iter().position(|header| header == name)?;
+
+        self.fields.get(i).map(|field| &**field)
+    }
+
+    /// Returns the column names read from the first record
+    pub fn headers(&self) -> &'r [Cow<'_, str>] {
+        self.headers
+    }
+}
+
+impl<'a> Deref for RecordView<'_, 'a> {
+    type Target = [Cow<'a, str>];
+
+    fn deref(&self) -> &Self::Target {
+        self.fields
+    }
+}
+
+/// Parses the record at the start of `text`.
+///
+/// Returns the fields of the record, its length in bytes including the terminator, and whether the
+/// terminator was found before the end of `text`.
+pub(crate) fn parse_record(
+    text: &str,
+    delimiter: char,
+    terminator: char,
+    literal: bool,
+) -> (Vec<Cow<'_, str>>, usize, bool) {
+    let mut row = CsvRow::new(text, delimiter, literal);
+    row.terminator = Some(terminator);
+
+    // A blank line is a record without any fields, just like an empty CsvRow
+    if let Some(length) = row.terminator_len(text) {
+        return (Vec::new(), length, true);
+    }
+
+    let record = row.by_ref().collect();
+
+    (record, row.byte_pos.min(text.len()), row.terminated)
+}
+
+/// Checks that every record of `document` is RFC-4180 compliant, reporting every problem rather than the first.
+///
+/// Each record is parsed as `try_fields` parses a strict CsvRow, and must hold as many fields as the first record.
+/// Each problem is reported as a `CsvError::AtLine` holding the line on which the record starts, while the offset
+/// of an error within it is relative to the start of the record. A blank line is a record without any fields.
+///
+/// # Arguments
+///
+/// * `document` - A string slice that holds the records, separated by newlines
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{validate, CsvError};
+/// let document = "a,b\nc,d\"\ne,\"f\n\"";
+/// let errors = validate(document, ',').unwrap_err();
+///
+/// assert_eq!(
+///     vec![CsvError::AtLine {
+///         line: 2,
+///         error: Box::new(CsvError::MalformedField { byte_pos: 3 }),
+///     }],
+///     errors
+/// )
+/// ```
+pub fn validate(document: &str, delimiter: char) -> Result<(), Vec<CsvError>> {
+    let mut errors = Vec::new();
+    let mut expected = None;
+    let mut line = 1;
+    let mut rest = document;
+
+    while !rest.is_empty() {
+        let (record, next) = rest.split_at(record_len(rest, delimiter, '\n'));
+
+        let mut row = CsvRow::new(record, delimiter, false).strict(true);
+        row.terminator = Some('\n');
+
+        let mut found = 0;
+
+        // A blank line holds no fields, though a CsvRow over it would yield one empty field
+        if row.terminator_len(record).is_none() {
+            for field in row.try_fields() {
+                found += 1;
+
+                if let Err(error) = field {
+                    errors.push(CsvError::AtLine {
+                        line,
+                        error: Box::new(error),
+                    });
+                }
+            }
+        }
+
+        let expected = *expected.get_or_insert(found);
+
+        if found != expected {
+            let error = match found < expected {
+                true => CsvError::TooFewFields { expected, found },
+                false => CsvError::TooManyFields { expected, found },
+            };
+
+            errors.push(CsvError::AtLine {
+                line,
+                error: Box::new(error),
+            });
+        }
+
+        line += record.matches('\n').count();
+        rest = next;
+    }
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors),
+    }
+}
+
+/// Returns the length in bytes of the record at the start of `text`, including its terminator, without
+/// parsing its fields.
+pub(crate) fn record_len(text: &str, delimiter: char, terminator: char) -> usize {
+    let mut row = CsvRow::new(text, delimiter, true);
+    row.terminator = Some(terminator);
+
+    if let Some(length) = row.terminator_len(text) {
+        return length;
+    }
+
+    while row.scan_field().is_some() {}
+
+    row.byte_pos.min(text.len())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn can_parse_document_with_embedded_newline() {
+        let text = "january,\"leap\nday\",march\napril,may,june";
+
+        let doc = CsvDocument::new(text, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![
+            vec!["january", "leap\nday", "march"],
+            vec!["april", "may", "june"],
+        ];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_literal_document_with_embedded_newline() {
+        let text = "january,\"leap\nday\"\napril,may";
+
+        let doc = CsvDocument::new(text, ',', true);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["january", "\"leap\nday\""], vec!["april", "may"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_document_with_emoji_delimiter() {
+        let text = "a🦙b🦙\nc🦙\"d\n🦙\"";
+
+        let doc = CsvDocument::new(text, '🦙', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b", ""], vec!["c", "d\n🦙"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trailing_newline_does_not_yield_record() {
+        let text = "a,b\nc,d\n";
+
+        let doc = CsvDocument::new(text, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b"], vec!["c", "d"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn blank_line_yields_empty_record() {
+        let text = "a,b\n\nc,\n";
+
+        let doc = CsvDocument::new(text, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b"], vec![], vec!["c", ""]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_empty_document() {
+        let doc = CsvDocument::new("", ',', false);
+
+        assert_eq!(doc.count(), 0)
+    }
+
+    #[test]
+    fn skips_comment_lines() {
+        let text = "# note\na,b,c\n  # indented note\nd,e,f\n#";
+
+        let doc = CsvDocument::new(text, ',', false).comment('#');
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b", "c"], vec!["d", "e", "f"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn does_not_skip_comment_char_within_record() {
+        let text = "a,\"b\n# not a comment\",c\nd,#e";
+
+        let doc = CsvDocument::new(text, ',', false).comment('#');
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b\n# not a comment", "c"], vec!["d", "#e"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn comment_lines_are_records_without_comment_char() {
+        let text = "# note\na,b,c";
+
+        let doc = CsvDocument::new(text, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["# note"], vec!["a", "b", "c"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn maps_records_to_headers() {
+        let text = "month,days\njanuary,31\n\"leap, february\",29";
+
+        let records: Vec<_> = CsvDocument::new(text, ',', false)
+            .with_headers()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(2, records.len());
+        assert_eq!("january", records[0]["month"]);
+        assert_eq!("31", records[0]["days"]);
+        assert_eq!("leap, february", records[1]["month"]);
+        assert_eq!("29", records[1]["days"]);
+    }
+
+    #[test]
+    fn duplicate_headers_keep_first_field() {
+        let text = "name,name,city\nAda,Lovelace,London";
+
+        let record = CsvDocument::new(text, ',', false)
+            .with_headers()
+            .next()
+            .unwrap()
+            .unwrap();
+        let names: Vec<_> = CsvDocument::new(text, ',', false)
+            .records_as(|record| record.get("name").map(str::to_owned))
+            .collect();
+
+        assert_eq!("Ada", record["name"]);
+        assert_eq!(2, record.len());
+        assert_eq!(vec![Some(String::from("Ada"))], names);
+    }
+
+    #[test]
+    fn skips_preamble_rows() {
+        let text = "Report\n\"generated\nby, hand\"\nmonth,days\njanuary,31";
+
+        let doc = CsvDocument::new(text, ',', false).skip_rows(2);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["month", "days"], vec!["january", "31"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(
+            Ok(()),
+            CsvDocument::new(text, ',', false)
+                .skip_rows(2)
+                .check_field_counts()
+        );
+        assert_eq!(0, CsvDocument::new(text, ',', false).skip_rows(5).count())
+    }
+
+    #[test]
+    fn builds_typed_records_with_closure() {
+        #[derive(Debug, PartialEq)]
+        struct Month {
+            name: String,
+            days: u32,
+        }
+
+        let text = "month,days\njanuary,31\n\"leap, february\",29";
+
+        let months: Vec<_> = CsvDocument::new(text, ',', false)
+            .records_as(|record| Month {
+                name: record[0].to_string(),
+                days: record.get("days").unwrap().parse().unwrap(),
+            })
+            .collect();
+
+        let vec_t = vec![
+            Month {
+                name: "january".into(),
+                days: 31,
+            },
+            Month {
+                name: "leap, february".into(),
+                days: 29,
+            },
+        ];
+
+        assert_eq!(vec_t, months)
+    }
+
+    #[test]
+    fn maps_missing_fields_to_empty_and_reports_extra_fields() {
+        let text = "month,days\njanuary\nfebruary,28,extra";
+
+        let mut records = CsvDocument::new(text, ',', false).with_headers();
+
+        assert_eq!(["month", "days"], records.headers());
+        assert_eq!(
+            Some(""),
+            records.next().unwrap().unwrap().get("days").map(|f| &**f)
+        );
+        assert_eq!(
+            Some(Err(CsvError::TooManyFields {
+                expected: 2,
+                found: 3
+            })),
+            records.next()
+        );
+        assert_eq!(None, records.next());
+    }
+
+    #[test]
+    fn check_field_counts_flags_short_row() {
+        let text = "# header\nmonth,days\n\"leap,\nfeb\",29\njanuary,31\nmarch\napril,30";
+
+        let doc = CsvDocument::new(text, ',', false).comment('#');
+
+        assert_eq!(Err((6, 2, 1)), doc.check_field_counts());
+    }
+
+    #[test]
+    fn check_field_counts_accepts_consistent_rows() {
+        let text = "month,days\n\"leap, feb\",29\njanuary,31\n";
+
+        let doc = CsvDocument::new(text, ',', false);
+
+        assert_eq!(Ok(()), doc.check_field_counts());
+    }
+
+    #[test]
+    fn can_parse_crlf_terminated_document() {
+        let text = "a,\"b\r\nc\"\r\n\r\nd,\"e\"\r\nf,\r\n";
+
+        let doc = CsvDocument::new(text, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b\r\nc"], vec![], vec!["d", "e"], vec!["f", ""]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_record_separator_terminated_document() {
+        let text = "# note\u{1E}a,\"b\u{1E}\nc\"\u{1E}\u{1E}d,e\n\u{1E}";
+
+        let doc = CsvDocument::new(text, ',', false)
+            .terminator('\u{1E}')
+            .comment('#');
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b\u{1E}\nc"], vec![], vec!["d", "e\n"]];
+        let vec_r: Vec<_> = doc.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn validate_reports_every_problem_with_its_line() {
+        let text = "month,days\n\"leap,\nfeb\",29\njan\"uary,31\nmarch\napril,30\n";
+
+        let errors = validate(text, ',').unwrap_err();
+
+        assert_eq!(
+            vec![
+                CsvError::AtLine {
+                    line: 4,
+                    error: Box::new(CsvError::MalformedField { byte_pos: 3 }),
+                },
+                CsvError::AtLine {
+                    line: 5,
+                    error: Box::new(CsvError::TooFewFields {
+                        expected: 2,
+                        found: 1
+                    }),
+                },
+            ],
+            errors
+        );
+    }
+
+    #[test]
+    fn validate_accepts_compliant_document() {
+        let text = "month,days\r\n\"The \"\"Coder\"\"\r\nMan\",29\r\n";
+
+        assert_eq!(Ok(()), validate(text, ','));
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::borrow::Cow;
+use core::iter::FusedIterator;
+
+use encoding_rs::WINDOWS_1252;
+
+use crate::CsvRowBytes;
+
+impl<'a> CsvRowBytes<'a> {
+    /// Returns an iterator that decodes each remaining field from Windows-1252 into UTF-8
+    ///
+    /// Each field is split and unescaped as bytes first, then decoded on its own. A field of only ASCII is
+    /// borrowed from `line`, so its span within `line` is kept. Requires the `encoding` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRowBytes;
+    /// let row = b"caf\xE9,\"it\x92s\"";
+    /// let vec_r: Vec<_> = CsvRowBytes::new(row, b',', false).decode_windows_1252().collect();
+    ///
+    /// assert_eq!(vec!["café", "it’s"], vec_r)
+    /// ```
+    pub fn decode_windows_1252(self) -> Windows1252Fields<'a> {
+        Windows1252Fields { row: self }
+    }
+}
+
+/// An iterator over the fields of a CsvRowBytes that decodes each one from Windows-1252
+///
+/// Created by `CsvRowBytes::decode_windows_1252`.
+pub struct Windows1252Fields<'a> {
+    row: CsvRowBytes<'a>,
+}
+
+impl<'a> Iterator for Windows1252Fields<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Every byte is a Windows-1252 char, so decoding never fails
+        Some(match self.row.next()? {
+            Cow::Borrowed(field) => WINDOWS_1252.decode_without_bom_handling(field).0,
+            Cow::Owned(field) => WINDOWS_1252
+                .decode_without_bom_handling(&field)
+                .0
+                .into_owned()
+                .into(),
+        })
+    }
+}
+
+impl FusedIterator for Windows1252Fields<'_> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn decodes_curly_apostrophe() {
+        let row = b"it\x92s,\"\x93quoted\x94 \"\"\x92\"\"\",plain";
+
+        let csv = CsvRowBytes::new(row, b',', false).decode_windows_1252();
+
+        let vec_t: Vec<_> = vec!["it’s", "“quoted” \"’\"", "plain"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn borrows_ascii_fields() {
+        let mut csv = CsvRowBytes::new(b"ascii,\x92", b',', false).decode_windows_1252();
+
+        assert!(matches!(csv.next(), Some(Cow::Borrowed("ascii"))));
+        assert!(matches!(csv.next(), Some(Cow::Owned(_))));
+    }
+}
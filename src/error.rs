@@ -0,0 +1,85 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+
+/// An error describing why a field could not be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CsvError {
+    /// A field opens with a quote that is never closed. `byte_pos` is the offset of the opening quote.
+    UnterminatedQuote { byte_pos: usize },
+    /// A quoted field contains a quote that is neither escaped nor closing. `byte_pos` is the offset of that quote.
+    UnexpectedQuote { byte_pos: usize },
+    /// A quoted field is closed and then followed by more text, which strict mode rejects. `byte_pos` is the
+    /// offset of the text right after the closing quote.
+    TrailingDataAfterQuote { byte_pos: usize },
+    /// An unquoted field contains a quote, which strict mode rejects. `byte_pos` is the offset of that quote.
+    MalformedField { byte_pos: usize },
+    /// A field is longer than the limit set with `CsvRow::max_field_len`. `byte_pos` is the offset of the field.
+    FieldTooLong { byte_pos: usize },
+    /// A field contains a NUL char, which `CsvRow::reject_nul` rejects. `byte_pos` is the offset of the NUL.
+    NulByte { byte_pos: usize },
+    /// A row holds fewer fields than `expected`
+    TooFewFields { expected: usize, found: usize },
+    /// A row holds more fields than `expected`
+    TooManyFields { expected: usize, found: usize },
+    /// A record of a document is malformed. `line` is the line on which the record starts, counting from 1.
+    AtLine { line: usize, error: Box<CsvError> },
+    /// A row could not be deserialized into the requested type
+    Deserialize(String),
+    /// A value could not be serialized into a row
+    Serialize(String),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::UnterminatedQuote { byte_pos } => {
+                write!(f, "unterminated quote at byte {byte_pos}")
+            }
+            CsvError::UnexpectedQuote { byte_pos } => {
+                write!(f, "unexpected quote at byte {byte_pos}")
+            }
+            CsvError::TrailingDataAfterQuote { byte_pos } => {
+                write!(f, "text after closing quote at byte {byte_pos}")
+            }
+            CsvError::MalformedField { byte_pos } => {
+                write!(f, "quote in unquoted field at byte {byte_pos}")
+            }
+            CsvError::FieldTooLong { byte_pos } => {
+                write!(f, "field at byte {byte_pos} is too long")
+            }
+            CsvError::NulByte { byte_pos } => write!(f, "NUL byte at byte {byte_pos}"),
+            CsvError::TooFewFields { expected, found } => {
+                write!(f, "expected {expected} fields but found only {found}")
+            }
+            CsvError::TooManyFields { expected, found } => {
+                write!(f, "expected {expected} fields but found {found}")
+            }
+            CsvError::AtLine { line, error } => write!(f, "line {line}: {error}"),
+            CsvError::Deserialize(message) => write!(f, "{message}"),
+            CsvError::Serialize(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl core::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            CsvError::AtLine { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn displays_error_with_position() {
+        let error = CsvError::UnterminatedQuote { byte_pos: 8 };
+
+        assert_eq!("unterminated quote at byte 8", error.to_string())
+    }
+}
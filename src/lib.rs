@@ -1,12 +1,286 @@
-use std::borrow::Cow;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::num::ParseFloatError;
+use core::ops::Range;
+use core::str::FromStr;
+
+#[cfg(feature = "async")]
+mod async_reader;
+mod buf;
+mod builder;
+mod bytes;
+mod constant;
+#[cfg(feature = "serde")]
+mod de;
+mod document;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod error;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "serde")]
+mod ser;
+mod writer;
+
+/// The number of chars of `line` shown by the `Debug` impl of `CsvRow`
+const DEBUG_PREVIEW_LEN: usize = 32;
+
+#[cfg(feature = "async")]
+pub use async_reader::AsyncCsvReader;
+pub use buf::CsvRowBuf;
+pub use builder::CsvRowBuilder;
+pub use bytes::{CsvRowBytes, LossyFields};
+pub use constant::CsvRowConst;
+#[cfg(feature = "serde")]
+pub use de::from_row;
+pub use document::{validate, CsvDocument, NamedRecords, RecordView, RecordsAs};
+#[cfg(feature = "encoding")]
+pub use encoding::Windows1252Fields;
+pub use error::CsvError;
+#[cfg(feature = "rayon")]
+pub use parallel::par_records;
+#[cfg(feature = "std")]
+pub use reader::CsvReader;
+#[cfg(feature = "serde")]
+pub use ser::to_row;
+#[cfg(feature = "std")]
+pub use writer::CsvWriter;
+pub use writer::{join, write_row, Record, Row};
+
+/// The convention used to escape a quote within a field
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EscapeStyle {
+    /// A quote is escaped by doubling it, as in RFC-4180 (`"a""b"`)
+    #[default]
+    Doubled,
+    /// A quote is escaped with a preceding backslash, and a backslash with another backslash (`"a\"b"`)
+    Backslash,
+    /// A quote is escaped with a preceding escape char, and the escape char with another one (`"a^"b"`)
+    Char(char),
+}
+
+impl EscapeStyle {
+    /// Returns the char that escapes a quote, or None if quotes are escaped by doubling
+    fn escape_char(self) -> Option<char> {
+        match self {
+            EscapeStyle::Doubled => None,
+            EscapeStyle::Backslash => Some('\\'),
+            EscapeStyle::Char(escape) => Some(escape),
+        }
+    }
+}
+
+/// Which whitespace is trimmed from the fields of a CsvRow
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrimMode {
+    /// No whitespace is trimmed
+    #[default]
+    Off,
+    /// Whitespace around each field is trimmed, but the contents of a quoted field are kept as they are (`" a "`)
+    Unquoted,
+    /// Whitespace around each field is trimmed, and so is the whitespace within the quotes of a quoted field
+    All,
+}
+
+/// How much of the quoting of each field is undone by a CsvRow
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FieldMode {
+    /// Enclosing quotes are removed and escaped quotes unescaped (`a"b`)
+    #[default]
+    Unescaped,
+    /// Enclosing quotes are kept but escaped quotes are unescaped (`"a"b"`)
+    KeepQuotes,
+    /// Enclosing quotes are removed but escaped quotes are kept as they are (`a""b`)
+    StripQuotes,
+    /// Fields are returned exactly as they appear in the line (`"a""b"`)
+    Literal,
+}
+
+impl From<bool> for FieldMode {
+    /// Returns `Literal` if `literal` is true and `Unescaped` otherwise, as with the `literal` argument of `CsvRow::new`
+    fn from(literal: bool) -> FieldMode {
+        match literal {
+            true => FieldMode::Literal,
+            false => FieldMode::Unescaped,
+        }
+    }
+}
+
+/// How strictly a CsvRow follows the quoting rules of RFC-4180
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QuoteMode {
+    /// A field is quoted only if it both opens and closes with a quote, and a lone quote is a field of its own
+    #[default]
+    Standard,
+    /// Quotes are read as Python's `csv` module reads them without `strict`: a quote opens a quoted field only at
+    /// its start, text after a closing quote is appended to the field, and an unterminated quote runs to the end of
+    /// the line (`"feb"ruary` is `february`)
+    Relaxed,
+}
+
+/// What a CsvRow over an empty line yields
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmptyLine {
+    /// An empty line has no fields
+    #[default]
+    NoFields,
+    /// An empty line is a single empty field, as a line of one column with no value would be
+    OneField,
+}
+
+/// The separator between fields
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Delimiter<'a> {
+    /// A single character, such as `,` or `\t`
+    Char(char),
+    /// A sequence of one or more characters, such as `||` or `::`
+    Str(&'a str),
+    /// Any one of several characters, such as `,` or `\t`
+    Set(&'a [char]),
+}
+
+impl Delimiter<'_> {
+    /// Returns the length in bytes of the delimiter if `s` starts with it
+    fn prefix_len(&self, s: &str) -> Option<usize> {
+        match *self {
+            Delimiter::Char(d) => s.starts_with(d).then_some(d.len_utf8()),
+            Delimiter::Str(d) => s.starts_with(d).then_some(d.len()),
+            Delimiter::Set(d) => s
+                .chars()
+                .next()
+                .filter(|c| d.contains(c))
+                .map(char::len_utf8),
+        }
+    }
+
+    /// Returns the length in bytes of the delimiter if `s` ends with it
+    fn suffix_len(&self, s: &str) -> Option<usize> {
+        match *self {
+            Delimiter::Char(d) => s.ends_with(d).then_some(d.len_utf8()),
+            Delimiter::Str(d) => s.ends_with(d).then_some(d.len()),
+            Delimiter::Set(d) => s
+                .chars()
+                .next_back()
+                .filter(|c| d.contains(c))
+                .map(char::len_utf8),
+        }
+    }
+
+    /// Returns the byte offset and the length in bytes of the first occurrence of the delimiter in `s`
+    fn find_in(&self, s: &str) -> Option<(usize, usize)> {
+        match *self {
+            Delimiter::Char(d) => s.find(d).map(|i| (i, d.len_utf8())),
+            Delimiter::Str(d) => s.find(d).map(|i| (i, d.len())),
+            Delimiter::Set(d) => s
+                .char_indices()
+                .find(|(_, c)| d.contains(c))
+                .map(|(i, c)| (i, c.len_utf8())),
+        }
+    }
+
+    /// Returns whether two occurrences of the delimiter can overlap, as `::` does in `:::`
+    ///
+    /// Which of the overlapping occurrences separates two fields depends on the text before them, so such a
+    /// delimiter can only be matched from the front.
+    fn overlaps_itself(&self) -> bool {
+        match *self {
+            Delimiter::Str(d) => {
+                (1..d.len()).any(|i| d.is_char_boundary(i) && d.ends_with(&d[..i]))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the byte offset and the length in bytes of the last occurrence of the delimiter in `s`
+    fn rfind_in(&self, s: &str) -> Option<(usize, usize)> {
+        match *self {
+            Delimiter::Char(d) => s.rfind(d).map(|i| (i, d.len_utf8())),
+            Delimiter::Str(d) => s.rfind(d).map(|i| (i, d.len())),
+            Delimiter::Set(d) => s
+                .char_indices()
+                .rfind(|(_, c)| d.contains(c))
+                .map(|(i, c)| (i, c.len_utf8())),
+        }
+    }
+
+    /// Returns the number of non-overlapping occurrences of the delimiter in `s`
+    fn count_in(&self, s: &str) -> usize {
+        match *self {
+            Delimiter::Char(d) => s.matches(d).count(),
+            Delimiter::Str(d) => s.matches(d).count(),
+            Delimiter::Set(d) => s.matches(d).count(),
+        }
+    }
+}
+
+/// A field that tells a bare empty field apart from an empty quoted one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Field<'a> {
+    /// An empty field without quotes, such as the second field of `a,,b`
+    Null,
+    /// An empty quoted field, such as the second field of `a,"",b`
+    Empty,
+    /// A field holding a value
+    Value(Cow<'a, str>),
+}
+
+/// The quoting rules a parser or writer is configured with, for describing the dialect it reads or writes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dialect<'a> {
+    /// The separator between fields
+    pub delimiter: Delimiter<'a>,
+    /// The char that encloses a quoted field, or None if quotes have no special meaning
+    pub quote: Option<char>,
+    /// How a quote is escaped within a quoted field
+    pub escape_style: EscapeStyle,
+    /// The char that ends a record, or None if the line is a single record that runs to its end
+    pub terminator: Option<char>,
+}
+
+/// The position of the next field to be parsed from the front of a CsvRow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    /// The number of the field, counting from 1
+    pub field: usize,
+    /// The byte offset of the field within `line`
+    pub byte: usize,
+}
+
+#[derive(Clone)]
 pub struct CsvRow<'a> {
     pub line: &'a str,
-    pub delimiter: char,
-    pub literal: bool,
-    char_pos: usize,
+    pub delimiter: Delimiter<'a>,
+    pub mode: FieldMode,
+    quote: char,
+    quoting: bool,
+    escape_style: EscapeStyle,
+    trim_mode: TrimMode,
+    trim_chars: Option<&'a [char]>,
+    skip_space_before_quote: bool,
+    strict: bool,
+    collapse_delimiters: bool,
+    trim_cr: bool,
+    max_field_len: Option<usize>,
+    reject_nul: bool,
+    empty_line: EmptyLine,
+    quote_mode: QuoteMode,
+    terminator: Option<char>,
+    terminated: bool,
     byte_pos: usize,
-    prev_char: Option<char>,
+    field_pos: usize,
+    end: usize,
+    raw: Option<&'a str>,
 }
 
 impl<'a> CsvRow<'a> {
@@ -21,7 +295,7 @@ impl<'a> CsvRow<'a> {
     /// # Examples
     ///
     /// ```
-    /// use CsvRow::*;
+    /// use csvrow::CsvRow;
     /// let row = "a,b,c,d";
     /// let csv = CsvRow::new(row, ',', false);
     /// let vec_t: Vec<_> = vec!["a", "b", "c", "d"];
@@ -29,297 +303,5348 @@ impl<'a> CsvRow<'a> {
     ///
     /// assert_eq!(vec_t[..], vec_r[..])
     /// ```
-    pub fn new(line: &str, delimiter: char, literal: bool) -> CsvRow {
+    pub fn new(line: &'a str, delimiter: char, literal: bool) -> CsvRow<'a> {
+        CsvRow::with_quote(line, delimiter, literal, '"')
+    }
+
+    /// Creates a new CsvRow along with the number of fields it will yield, counted as `field_count()` does
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"a,"b,c",d"#;
+    /// let (count, csv) = CsvRow::with_counted(row, ',', false);
+    /// let mut columns = Vec::with_capacity(count);
+    /// columns.extend(csv);
+    ///
+    /// assert_eq!(3, count)
+    /// ```
+    pub fn with_counted(line: &'a str, delimiter: char, literal: bool) -> (usize, CsvRow<'a>) {
+        (
+            CsvRow::field_count(line, delimiter),
+            CsvRow::new(line, delimiter, literal),
+        )
+    }
+
+    /// Creates a new CsvRow over tab-separated values
+    ///
+    /// A tab within a quoted field is part of the field, as with any other delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the tab-separated fields
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a\tb\tc";
+    /// let csv = CsvRow::tsv(row, false);
+    /// let vec_t: Vec<_> = vec!["a", "b", "c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn tsv(line: &'a str, literal: bool) -> CsvRow<'a> {
+        CsvRow::new(line, '\t', literal)
+    }
+
+    /// Creates a new CsvRow that encloses fields with `quote` instead of `"`
+    ///
+    /// Escaped quotes within a field are expected to be doubled, so with a quote of `'`
+    /// the sequence `''` is unescaped to `'`.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    /// * `quote` - A char that represents the quote used to enclose fields
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a,'b,c',d";
+    /// let csv = CsvRow::with_quote(row, ',', false, '\'');
+    /// let vec_t: Vec<_> = vec!["a", "b,c", "d"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn with_quote(line: &'a str, delimiter: char, literal: bool, quote: char) -> CsvRow<'a> {
         CsvRow {
             line,
-            delimiter,
-            literal,
+            delimiter: Delimiter::Char(delimiter),
+            mode: literal.into(),
+            quote,
+            quoting: true,
+            escape_style: EscapeStyle::Doubled,
+            trim_mode: TrimMode::Off,
+            trim_chars: None,
+            skip_space_before_quote: false,
+            strict: false,
+            collapse_delimiters: false,
+            trim_cr: false,
+            max_field_len: None,
+            reject_nul: false,
+            empty_line: EmptyLine::NoFields,
+            quote_mode: QuoteMode::Standard,
+            terminator: None,
+            terminated: false,
             byte_pos: 0,
-            char_pos: 0,
-            prev_char: None,
+            field_pos: 0,
+            end: line.len(),
+            raw: None,
         }
     }
-}
 
-impl<'a> Iterator for CsvRow<'a> {
-    type Item = Cow<'a, str>;
+    /// Creates a new CsvRow whose fields are separated by a multi-character delimiter
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A string slice that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a||b||c";
+    /// let csv = CsvRow::with_str_delimiter(row, "||", false);
+    /// let vec_t: Vec<_> = vec!["a", "b", "c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn with_str_delimiter(line: &'a str, delimiter: &'a str, literal: bool) -> CsvRow<'a> {
+        assert!(!delimiter.is_empty(), "delimiter must not be empty");
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.byte_pos > self.line.len() || self.line.len() == 0 {
-            return None;
+        CsvRow {
+            delimiter: Delimiter::Str(delimiter),
+            ..CsvRow::new(line, ',', literal)
+        }
+    }
+
+    /// Creates a new CsvRow whose fields are separated by any one of `delimiters`
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiters` - A slice of chars that each represent a delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiters` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a,b\tc";
+    /// let csv = CsvRow::with_delimiters(row, &[',', '\t'], false);
+    /// let vec_t: Vec<_> = vec!["a", "b", "c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn with_delimiters(line: &'a str, delimiters: &'a [char], literal: bool) -> CsvRow<'a> {
+        assert!(!delimiters.is_empty(), "delimiters must not be empty");
+
+        CsvRow {
+            delimiter: Delimiter::Set(delimiters),
+            ..CsvRow::new(line, ',', literal)
         }
+    }
 
-        let charenum = self.line.char_indices().into_iter().skip(self.char_pos);
+    /// Creates a new CsvRow over fields that are known never to be quoted
+    ///
+    /// `line` is split at every delimiter, just as `str::split` would split it, without any of the bookkeeping
+    /// that quoted fields need. Every field is borrowed from `line` as it is written. If `line` does hold quotes
+    /// they are simply part of the fields, so a quoted delimiter still splits a field. As with any CsvRow, an
+    /// empty line holds no fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a,\"b,c\"";
+    /// let csv = CsvRow::unquoted(row, ',');
+    /// let vec_t: Vec<_> = vec!["a", "\"b", "c\""];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn unquoted(line: &'a str, delimiter: char) -> CsvRow<'a> {
+        CsvRow {
+            quoting: false,
+            ..CsvRow::new(line, delimiter, true)
+        }
+    }
 
-        let mut byte_length: usize = 0;
-        let mut quoted = false;
+    /// Sets the convention used to escape quotes within a field
+    ///
+    /// Defaults to `EscapeStyle::Doubled`. With `EscapeStyle::Backslash`, `\"` is unescaped to `"` and `\\` to `\`,
+    /// while doubled quotes are left as they are. `EscapeStyle::Char` does the same with an escape char of its own,
+    /// such as `^`, independently of the quote. Literal mode leaves the escape chars untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `escape_style` - An EscapeStyle that represents how quotes are escaped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, EscapeStyle};
+    /// let row = r#"a,"b\"c",d"#;
+    /// let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+    /// let vec_t: Vec<_> = vec!["a", "b\"c", "d"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn escape_style(mut self, escape_style: EscapeStyle) -> CsvRow<'a> {
+        self.escape_style = escape_style;
+        self
+    }
 
-        for (_, c) in charenum {
-            if byte_length == 0 && c == '"' {
-                quoted = true;
-            }
+    /// Sets how much of the quoting of each field is undone
+    ///
+    /// Overrides the `literal` argument the CsvRow was created with. `FieldMode::KeepQuotes` unescapes a field but
+    /// leaves it enclosed in its quotes, for re-emitting fields that are already quoted. `FieldMode::StripQuotes` does
+    /// the opposite, removing the enclosing quotes but leaving the escapes for a consumer that unescapes fields itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - A FieldMode that represents whether quotes are removed, unescaped, or kept as they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, FieldMode};
+    /// let row = r#"a,"b""c""#;
+    /// let csv = CsvRow::new(row, ',', false).mode(FieldMode::KeepQuotes);
+    /// let vec_t: Vec<_> = vec!["a", "\"b\"c\""];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn mode(mut self, mode: FieldMode) -> CsvRow<'a> {
+        self.mode = mode;
+        self
+    }
 
-            if c == self.delimiter {
-                if !quoted || (quoted && self.prev_char == Some('"')) {
-                    break;
-                }
-            }
+    /// Sets whether leading and trailing ASCII whitespace is trimmed from each field
+    ///
+    /// Trimming happens before quotes are detected, so a quoted field surrounded by whitespace is still
+    /// unescaped. Whitespace within the quotes is kept. Literal mode returns fields untrimmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `trim` - A bool that indicates whether fields should be trimmed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"january, "february", march"#;
+    /// let csv = CsvRow::new(row, ',', false).trim(true);
+    /// let vec_t: Vec<_> = vec!["january", "february", "march"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn trim(mut self, trim: bool) -> CsvRow<'a> {
+        self.trim_mode = match trim {
+            true => TrimMode::Unquoted,
+            false => TrimMode::Off,
+        };
+        self
+    }
+
+    /// Sets how whitespace is trimmed from each field
+    ///
+    /// `TrimMode::Unquoted` trims as `trim(true)` does, leaving the contents of a quoted field as they are, while
+    /// `TrimMode::All` also trims the whitespace within the quotes. Literal mode returns fields untrimmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `trim_mode` - A TrimMode that represents which whitespace should be trimmed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, TrimMode};
+    /// let row = r#" a , "  b  " "#;
+    /// let csv = CsvRow::new(row, ',', false).trim_mode(TrimMode::All);
+    /// let vec_t: Vec<_> = vec!["a", "b"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn trim_mode(mut self, trim_mode: TrimMode) -> CsvRow<'a> {
+        self.trim_mode = trim_mode;
+        self
+    }
+
+    /// Sets the chars trimmed from each field in place of ASCII whitespace
+    ///
+    /// The chars are trimmed before the field is checked for quotes, so a quoted field padded with them is still
+    /// unquoted. Trimming is turned on as `trim(true)` would if it is off; a `TrimMode` set before or after is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `trim_chars` - A slice of chars that should be trimmed from the edges of each field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"*a*,*"b*"*"#;
+    /// let csv = CsvRow::new(row, ',', false).trim_chars(&['*']);
+    /// let vec_t: Vec<_> = vec!["a", "b*"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn trim_chars(mut self, trim_chars: &'a [char]) -> CsvRow<'a> {
+        self.trim_chars = Some(trim_chars);
+
+        if self.trim_mode == TrimMode::Off {
+            self.trim_mode = TrimMode::Unquoted;
+        }
+        self
+    }
+
+    /// Sets whether ASCII whitespace between a delimiter and an opening quote is skipped
+    ///
+    /// Unlike `trim`, this leaves the whitespace of unquoted fields as it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_space_before_quote` - A bool that indicates whether whitespace before an opening quote is skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"january, "february", march"#;
+    /// let csv = CsvRow::new(row, ',', false).skip_space_before_quote(true);
+    /// let vec_t: Vec<_> = vec!["january", "february", " march"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn skip_space_before_quote(mut self, skip_space_before_quote: bool) -> CsvRow<'a> {
+        self.skip_space_before_quote = skip_space_before_quote;
+        self
+    }
+
+    /// Sets whether `try_fields` enforces RFC-4180 strictly
+    ///
+    /// By default `try_fields` only rejects quoted fields whose quotes are mismatched. In strict mode it also
+    /// rejects a quote within an unquoted field, such as `feb"ruary`, as `CsvError::MalformedField`, and reports
+    /// text after the closing quote of a quoted field, such as `"feb"ruary`, as `CsvError::TrailingDataAfterQuote`.
+    /// Iterating the CsvRow itself is always lenient.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - A bool that indicates whether fields should be validated strictly
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvError, CsvRow};
+    /// let row = r#"january,feb"ruary"#;
+    /// let mut csv = CsvRow::new(row, ',', false).strict(true).try_fields();
+    ///
+    /// assert_eq!(csv.next(), Some(Ok("january".into())));
+    /// assert_eq!(csv.next(), Some(Err(CsvError::MalformedField { byte_pos: 11 })));
+    /// ```
+    pub fn strict(mut self, strict: bool) -> CsvRow<'a> {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets whether a UTF-8 byte order mark at the very start of `line` is skipped
+    ///
+    /// Files exported by some spreadsheet tools begin with a BOM, which would otherwise be glued to the first
+    /// field. When `strip_bom` is true the BOM is removed from `line`, so it must be set before parsing begins.
+    ///
+    /// # Arguments
+    ///
+    /// * `strip_bom` - A bool that indicates whether a leading BOM should be skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "\u{FEFF}a,b,c";
+    /// let csv = CsvRow::new(row, ',', false).strip_bom(true);
+    /// let vec_t: Vec<_> = vec!["a", "b", "c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn strip_bom(mut self, strip_bom: bool) -> CsvRow<'a> {
+        if strip_bom && self.byte_pos == 0 && self.end == self.line.len() {
+            if let Some(line) = self.line.strip_prefix('\u{FEFF}') {
+                self.line = line;
+                self.end = line.len();
+            }
+        }
+
+        self
+    }
+
+    /// Sets whether a single carriage return at the end of `line` is ignored
+    ///
+    /// Splitting a CRLF file on `\n` leaves a `\r` at the end of each line, which would otherwise be glued to
+    /// the last field. When `trim_cr` is true the `\r` is left out of the last field, though `line` still holds it.
+    /// Literal mode returns fields as they are written, so in literal mode this has no effect, whichever of `mode`
+    /// and `trim_cr` is set first.
+    ///
+    /// # Arguments
+    ///
+    /// * `trim_cr` - A bool that indicates whether a trailing `\r` should be ignored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a,b,\"c\"\r";
+    /// let csv = CsvRow::new(row, ',', false).trim_cr(true);
+    /// let vec_t: Vec<_> = vec!["a", "b", "c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn trim_cr(mut self, trim_cr: bool) -> CsvRow<'a> {
+        self.trim_cr = trim_cr;
+        self
+    }
+
+    /// Sets whether a run of consecutive delimiters separates two fields as a single delimiter would
+    ///
+    /// This suits columns aligned with repeated spaces or tabs. Delimiters within a quoted field are part of
+    /// the field, so they are never collapsed. A delimiter at the start or end of `line` still separates an
+    /// empty field.
+    ///
+    /// # Arguments
+    ///
+    /// * `collapse_delimiters` - A bool that indicates whether consecutive delimiters should be treated as one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a   b \"c  d\"";
+    /// let csv = CsvRow::new(row, ' ', false).collapse_delimiters(true);
+    /// let vec_t: Vec<_> = vec!["a", "b", "c  d"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn collapse_delimiters(mut self, collapse_delimiters: bool) -> CsvRow<'a> {
+        self.collapse_delimiters = collapse_delimiters;
+        self
+    }
+
+    /// Sets the longest field, in bytes, that `try_fields` accepts
+    ///
+    /// A field longer than `max_field_len` is reported as `CsvError::FieldTooLong` before it is unescaped, which
+    /// guards against an unterminated quote in untrusted input swallowing the rest of a huge line. The length is
+    /// that of the raw field, including any quotes. Iterating the CsvRow itself never limits fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_field_len` - The longest field to accept, or `None` to accept fields of any length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvError, CsvRow};
+    /// let row = "a,abcdef,b";
+    /// let mut csv = CsvRow::new(row, ',', false).max_field_len(Some(4)).try_fields();
+    ///
+    /// assert_eq!(csv.next(), Some(Ok("a".into())));
+    /// assert_eq!(csv.next(), Some(Err(CsvError::FieldTooLong { byte_pos: 2 })));
+    /// assert_eq!(csv.next(), Some(Ok("b".into())));
+    /// ```
+    pub fn max_field_len(mut self, max_field_len: Option<usize>) -> CsvRow<'a> {
+        self.max_field_len = max_field_len;
+        self
+    }
+
+    /// Sets whether `try_fields` rejects a field containing a NUL char
+    ///
+    /// Some sinks, such as a Postgres `COPY`, cannot store NUL in text, so rows holding one can be rejected early
+    /// as `CsvError::NulByte`. Iterating the CsvRow itself always returns such fields as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `reject_nul` - A bool that indicates whether fields containing `\0` should be reported as errors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvError, CsvRow};
+    /// let row = "a,b\0c";
+    /// let mut csv = CsvRow::new(row, ',', false).reject_nul(true).try_fields();
+    ///
+    /// assert_eq!(csv.next(), Some(Ok("a".into())));
+    /// assert_eq!(csv.next(), Some(Err(CsvError::NulByte { byte_pos: 3 })));
+    /// ```
+    pub fn reject_nul(mut self, reject_nul: bool) -> CsvRow<'a> {
+        self.reject_nul = reject_nul;
+        self
+    }
+
+    /// Sets whether an empty line yields no fields or a single empty one
+    ///
+    /// Defaults to `EmptyLine::NoFields`. `EmptyLine::OneField` keeps the arity of a single-column file
+    /// consistent when some of its values are missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `empty_line` - An EmptyLine that represents the fields an empty line yields
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, EmptyLine};
+    /// let csv = CsvRow::new("", ',', false).empty_line_yields(EmptyLine::OneField);
+    /// let vec_t: Vec<_> = vec![""];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn empty_line_yields(mut self, empty_line: EmptyLine) -> CsvRow<'a> {
+        self.empty_line = empty_line;
+        self
+    }
+
+    /// Sets whether quotes follow RFC-4180 or the relaxed rules of Python's `csv` module
+    ///
+    /// Defaults to `QuoteMode::Standard`. With `EscapeStyle::Doubled`, `QuoteMode::Relaxed` splits and unescapes
+    /// fields as `csv.reader` does with its default dialect, so that a line Python reads leniently yields the same
+    /// fields here. Any other `EscapeStyle` corresponds to `csv.reader(..., escapechar=..., doublequote=False)`
+    /// instead: its escape char escapes whichever char follows it, and a doubled quote is not an escaped quote, so
+    /// Python's default of accepting both is not matched. `skip_space_before_quote` also skips the leading
+    /// whitespace of unquoted fields, as `skipinitialspace` does. Since every line is valid under these
+    /// rules, `try_fields` reports no quoting errors. A relaxed field can only be found by scanning from the start of
+    /// the line, so `next_back` rescans the fields before the last one.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote_mode` - A QuoteMode that represents how quotes within a field are read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, QuoteMode};
+    /// let row = r#"january,"feb"ruary,ma"rch,"a""b"#;
+    /// let csv = CsvRow::new(row, ',', false).quote_mode(QuoteMode::Relaxed);
+    /// let vec_t: Vec<_> = vec!["january", "february", "ma\"rch", "a\"b"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn quote_mode(mut self, quote_mode: QuoteMode) -> CsvRow<'a> {
+        self.quote_mode = quote_mode;
+        self
+    }
+}
+
+impl<'a> CsvRow<'a> {
+    /// Returns the number of fields in `line` without unescaping or allocating any of them
+    ///
+    /// Fields are found with the same quote-aware scan as `CsvRow::new(line, delimiter, false)`, so a delimiter
+    /// within a quoted field is not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"a,"b,c",d"#;
+    ///
+    /// assert_eq!(3, CsvRow::field_count(row, ','))
+    /// ```
+    pub fn field_count(line: &str, delimiter: char) -> usize {
+        let mut row = CsvRow::new(line, delimiter, true);
+        let mut count = 0;
+
+        while row.scan_field().is_some() {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns the field of `line` at the zero-based `index`, or `None` if there are too few fields
+    ///
+    /// The fields before `index` are skipped without being unescaped, and those after it are not parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `index` - The position of the field to return
+    /// * `literal` - A bool that indicates whether the parsed field should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"a,"b,c",d"#;
+    ///
+    /// assert_eq!(Some("b,c".into()), CsvRow::field(row, ',', 1, false));
+    /// assert_eq!(None, CsvRow::field(row, ',', 3, false));
+    /// ```
+    pub fn field(
+        line: &'a str,
+        delimiter: char,
+        index: usize,
+        literal: bool,
+    ) -> Option<Cow<'a, str>> {
+        let mut row = CsvRow::new(line, delimiter, literal);
+
+        for _ in 0..index {
+            row.scan_field()?;
+        }
+
+        row.next()
+    }
+
+    /// Returns an iterator over the remaining fields that reports malformed quoting as a CsvError
+    ///
+    /// A field that opens with a quote must close with one, and any quote in between must be escaped.
+    /// An error does not end the iteration; the next item is the field that follows the malformed one. Iterating the
+    /// CsvRow itself is lenient instead: a quote that is never closed runs to the end of the line, and the field is
+    /// returned as it is, opening quote included, so `"feb` below would be yielded as `"feb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvError, CsvRow};
+    /// let row = r#"january,"feb"#;
+    /// let mut csv = CsvRow::new(row, ',', false).try_fields();
+    ///
+    /// assert_eq!(csv.next(), Some(Ok("january".into())));
+    /// assert_eq!(csv.next(), Some(Err(CsvError::UnterminatedQuote { byte_pos: 8 })));
+    /// ```
+    pub fn try_fields(self) -> TryFields<'a> {
+        TryFields { row: self }
+    }
+
+    /// Collects the remaining fields into an array, failing unless there are exactly `N` of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvError, CsvRow};
+    /// let [name, city] = CsvRow::new(r#"Ada,"London, UK""#, ',', false).collect_array().unwrap();
+    ///
+    /// assert_eq!(("Ada", "London, UK"), (&*name, &*city));
+    ///
+    /// let result = CsvRow::new("Ada", ',', false).collect_array::<2>();
+    ///
+    /// assert_eq!(Err(CsvError::TooFewFields { expected: 2, found: 1 }), result)
+    /// ```
+    pub fn collect_array<const N: usize>(self) -> Result<[Cow<'a, str>; N], CsvError> {
+        let fields: Vec<_> = self.collect();
+        let found = fields.len();
+
+        fields.try_into().map_err(|_| match found < N {
+            true => CsvError::TooFewFields { expected: N, found },
+            false => CsvError::TooManyFields { expected: N, found },
+        })
+    }
+
+    /// Collects the remaining fields in strict mode, failing at the first malformed one
+    ///
+    /// This is `strict(true).try_fields()` collected into a `Result`, so the error is the one `try_fields` would
+    /// report, with its byte offset within `line`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvError, CsvRow};
+    /// let fields = CsvRow::new(r#"a,"b,c""#, ',', false).try_collect_strict();
+    ///
+    /// assert_eq!(Ok(vec!["a".into(), "b,c".into()]), fields);
+    ///
+    /// let fields = CsvRow::new(r#"a,b"c"#, ',', false).try_collect_strict();
+    ///
+    /// assert_eq!(Err(CsvError::MalformedField { byte_pos: 3 }), fields)
+    /// ```
+    pub fn try_collect_strict(self) -> Result<Vec<Cow<'a, str>>, CsvError> {
+        self.strict(true).try_fields().collect()
+    }
+
+    /// Collects the remaining fields into a `Vec` of owned strings, reserving room for all of them up front
+    ///
+    /// The fields are counted with the same scan as `count()` before any is unescaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let fields = CsvRow::new(r#"a,"b,c",d"#, ',', false).into_owned_vec();
+    ///
+    /// assert_eq!(vec!["a", "b,c", "d"], fields)
+    /// ```
+    pub fn into_owned_vec(self) -> Vec<String> {
+        let mut fields = Vec::with_capacity(self.clone().count());
+        fields.extend(self.map(Cow::into_owned));
+
+        fields
+    }
+
+    /// Returns whether the remaining fields are exactly `fields`, stopping at the first that differs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let csv = CsvRow::new(r#"a,"b,c",d"#, ',', false);
+    ///
+    /// assert!(csv.eq_fields(&["a", "b,c", "d"]))
+    /// ```
+    pub fn eq_fields<S: AsRef<str>>(self, fields: &[S]) -> bool {
+        self.eq(fields.iter().map(AsRef::as_ref))
+    }
+
+    /// Returns an iterator over the remaining fields that also yields the byte range of each field within `line`
+    ///
+    /// The range covers the raw field, including its enclosing quotes and any whitespace removed by `trim`, but
+    /// not the delimiter that follows it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"a,"b,c""#;
+    /// let vec_t: Vec<_> = vec![(0..1, "a".into()), (2..7, "b,c".into())];
+    /// let vec_r: Vec<_> = CsvRow::new(row, ',', false).spanned().collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn spanned(self) -> Spanned<'a> {
+        Spanned { row: self }
+    }
+
+    /// Returns an iterator that yields whether each remaining field was enclosed in quotes alongside its value
+    ///
+    /// The flag lets a row be written back out with the quoting of its source. It is reported for the raw field,
+    /// so it holds in literal mode as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = r#"a,"b,c""#;
+    /// let vec_t: Vec<_> = vec![(false, "a".into()), (true, "b,c".into())];
+    /// let vec_r: Vec<_> = CsvRow::new(row, ',', false).quoted_fields().collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn quoted_fields(self) -> QuotedFields<'a> {
+        QuotedFields { row: self }
+    }
+
+    /// Returns an iterator that passes each remaining field, once unescaped, through `f`
+    ///
+    /// This fuses a normalization such as lowercasing, or mapping `NULL` to an empty field, into the parse. `f`
+    /// may return its argument untouched, so fields it leaves alone are still borrowed from `line`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure that maps an unescaped field to the value to yield
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a,NULL,c";
+    /// let csv = CsvRow::new(row, ',', false).map_fields(|f| if f == "NULL" { "".into() } else { f });
+    /// let vec_t: Vec<_> = vec!["a", "", "c"];
+    /// let vec_r: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn map_fields<F>(self, f: F) -> MapFields<'a, F>
+    where
+        F: FnMut(Cow<'a, str>) -> Cow<'a, str>,
+    {
+        MapFields { row: self, f }
+    }
+
+    /// Returns an iterator over each overlapping pair of consecutive remaining fields
+    ///
+    /// Every field but the first and last appears in two pairs, so a row with fewer than two fields yields none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "a,b,c";
+    /// let vec_t: Vec<_> = vec![("a".into(), "b".into()), ("b".into(), "c".into())];
+    /// let vec_r: Vec<_> = CsvRow::new(row, ',', false).pairs().collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn pairs(self) -> Pairs<'a> {
+        Pairs {
+            row: self,
+            prev: None,
+        }
+    }
+
+    /// Returns an iterator over the remaining fields that yields `Field::Null` for a bare empty field and
+    /// `Field::Empty` for an empty quoted field
+    ///
+    /// In literal mode an empty quoted field keeps its quotes, so it is yielded as a `Field::Value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, Field};
+    /// let row = r#"a,,"""#;
+    /// let vec_t: Vec<_> = vec![Field::Value("a".into()), Field::Null, Field::Empty];
+    /// let vec_r: Vec<_> = CsvRow::new(row, ',', false).nullable().collect();
+    ///
+    /// assert_eq!(vec_t[..], vec_r[..])
+    /// ```
+    pub fn nullable(self) -> Nullable<'a> {
+        Nullable { row: self }
+    }
+
+    /// Returns an iterator that parses each remaining field, once unescaped, into a `T`
+    ///
+    /// An empty field is passed to `T::from_str` like any other, so its result is left to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "1,2,3";
+    /// let vec_r: Vec<u32> = CsvRow::new(row, ',', false).parse_fields().collect::<Result<_, _>>().unwrap();
+    ///
+    /// assert_eq!(vec![1, 2, 3], vec_r)
+    /// ```
+    pub fn parse_fields<T: FromStr>(self) -> ParseFields<'a, T> {
+        ParseFields {
+            row: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that parses each remaining field, once unescaped, into an `f64` whose decimal separator
+    /// is `decimal` rather than `.`
+    ///
+    /// Each `decimal` in a field is replaced with `.` before it is passed to `f64::from_str`, so fields written
+    /// with a `,` separator can be parsed from a `;` delimited row. No thousands separators are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimal` - A char that represents the decimal separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let row = "1,5;2,75";
+    /// let vec_r: Vec<f64> = CsvRow::new(row, ';', false).parse_decimals(',').collect::<Result<_, _>>().unwrap();
+    ///
+    /// assert_eq!(vec![1.5, 2.75], vec_r)
+    /// ```
+    pub fn parse_decimals(self, decimal: char) -> ParseDecimals<'a> {
+        ParseDecimals { row: self, decimal }
+    }
+
+    /// Returns the part of `line` that has not been parsed yet
+    ///
+    /// After a trailing delimiter the remainder is empty, though one empty field is still to come.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut csv = CsvRow::new("a,b,c", ',', false);
+    /// csv.next();
+    ///
+    /// assert_eq!("b,c", csv.remainder())
+    /// ```
+    pub fn remainder(&self) -> &'a str {
+        &self.line[self.byte_pos.min(self.fields_end())..self.fields_end()]
+    }
+
+    /// Calls `f` with the value of each remaining field, unescaping into a single buffer rather than allocating
+    /// a `String` for each field
+    ///
+    /// The slice passed to `f` may point into that buffer, which is overwritten by the next field, so it only lives
+    /// for the duration of the call. Copy it, for example with `to_owned()`, to keep it any longer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut line = String::new();
+    /// CsvRow::new(r#"a,"b ""c""""#, ',', false).for_each_unescaped(|field| line.push_str(field));
+    ///
+    /// assert_eq!("ab \"c\"", line)
+    /// ```
+    pub fn for_each_unescaped(mut self, mut f: impl FnMut(&str)) {
+        let mut scratch = String::new();
+
+        while let Some((_, field)) = self.scan_field() {
+            f(self.unescape_field_into(field, &mut scratch));
+        }
+    }
+
+    /// Advances past the next `n` fields without unescaping them, respecting quoted fields
+    ///
+    /// Unlike `Iterator::skip`, no `String` is built for a skipped field that needs unescaping.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of fields to skip
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut csv = CsvRow::new(r#""a,b",c,d"#, ',', false);
+    /// csv.skip_fields(2);
+    ///
+    /// assert_eq!(Some("d".into()), csv.next())
+    /// ```
+    pub fn skip_fields(&mut self, n: usize) {
+        for _ in 0..n {
+            if self.scan_field().is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Rewinds the iterator so that `line` is parsed again from its first field
+    ///
+    /// The delimiter, quote and other options are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut csv = CsvRow::new("a,b", ',', false);
+    /// let first: Vec<_> = csv.by_ref().collect();
+    /// csv.reset();
+    /// let second: Vec<_> = csv.collect();
+    ///
+    /// assert_eq!(first, second)
+    /// ```
+    pub fn reset(&mut self) {
+        self.byte_pos = 0;
+        self.field_pos = 0;
+        self.end = self.line.len();
+        self.terminated = false;
+        self.raw = None;
+    }
+
+    /// Returns the field last returned by `next()` or `next_back()` as it is written in `line`
+    ///
+    /// The raw field keeps its enclosing quotes, escapes and any whitespace that `trim` removes, so it can be
+    /// logged alongside the unescaped value. Returns `None` until a field has been returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut csv = CsvRow::new(r#"x,"a""b""#, ',', false);
+    /// csv.next();
+    ///
+    /// assert_eq!(Some("a\"b".into()), csv.next());
+    /// assert_eq!(Some(r#""a""b""#), csv.raw())
+    /// ```
+    pub fn raw(&self) -> Option<&'a str> {
+        self.raw
+    }
+
+    /// Returns the delimiter, quote, escape style and terminator this CsvRow parses with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, Delimiter, Dialect, EscapeStyle};
+    /// let csv = CsvRow::with_quote("a;'b'", ';', false, '\'');
+    /// let dialect = Dialect {
+    ///     delimiter: Delimiter::Char(';'),
+    ///     quote: Some('\''),
+    ///     escape_style: EscapeStyle::Doubled,
+    ///     terminator: None,
+    /// };
+    ///
+    /// assert_eq!(dialect, csv.dialect())
+    /// ```
+    pub fn dialect(&self) -> Dialect<'a> {
+        Dialect {
+            delimiter: self.delimiter,
+            quote: self.quoting.then_some(self.quote),
+            escape_style: self.escape_style,
+            terminator: self.terminator,
+        }
+    }
+
+    /// Returns the position of the next field that `next()` will parse
+    ///
+    /// Once every field has been parsed, the position is just past the last field, at the end of `line`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvRow, Position};
+    /// let mut csv = CsvRow::new(r#""a,b",c"#, ',', false);
+    /// csv.next();
+    ///
+    /// assert_eq!(Position { field: 2, byte: 6 }, csv.cursor())
+    /// ```
+    pub fn cursor(&self) -> Position {
+        Position {
+            field: self.field_pos + 1,
+            byte: self.byte_pos.min(self.line_len()),
+        }
+    }
+
+    /// Returns the number of bytes of `line` that fields have been parsed from the front, including their delimiters
+    ///
+    /// This is the byte of `cursor()`, so it never exceeds `line.len()` and reaches the end of the fields once every
+    /// field has been parsed from the front, which makes it suitable for reporting progress through a long line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut csv = CsvRow::new("a,bc,d", ',', false);
+    /// csv.next();
+    ///
+    /// assert_eq!(2, csv.bytes_consumed());
+    ///
+    /// csv.by_ref().for_each(drop);
+    ///
+    /// assert_eq!(6, csv.bytes_consumed())
+    /// ```
+    pub fn bytes_consumed(&self) -> usize {
+        self.cursor().byte
+    }
+
+    /// Returns whether any fields remain to be parsed, without parsing one
+    ///
+    /// A trailing delimiter is followed by one more empty field, so `a,` still has a field after `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvRow;
+    /// let mut csv = CsvRow::new("a,b", ',', false);
+    ///
+    /// while csv.has_more() {
+    ///     csv.next();
+    /// }
+    ///
+    /// assert_eq!(None, csv.next())
+    /// ```
+    pub fn has_more(&self) -> bool {
+        !self.finished()
+    }
+
+    /// Returns whether whitespace around each field is trimmed
+    fn trims(&self) -> bool {
+        self.trim_mode != TrimMode::Off
+    }
+
+    /// Returns whether `c` is trimmed from the edges of a field, when trimming is on
+    fn is_trim_char(&self, c: char) -> bool {
+        match self.trim_chars {
+            Some(chars) => chars.contains(&c),
+            None => c.is_ascii_whitespace(),
+        }
+    }
+
+    /// Returns the length of `line` without the trailing `\r` that `trim_cr` leaves out of the last field
+    fn line_len(&self) -> usize {
+        match self.trim_cr && self.mode != FieldMode::Literal && self.line.ends_with('\r') {
+            true => self.line.len() - 1,
+            false => self.line.len(),
+        }
+    }
+
+    /// Returns the byte offset within `line` at which the remaining fields end
+    fn fields_end(&self) -> usize {
+        self.end.min(self.line_len())
+    }
+
+    /// Returns whether every field has been parsed
+    fn finished(&self) -> bool {
+        self.byte_pos > self.fields_end()
+            || self.line_len() == 0 && self.empty_line == EmptyLine::NoFields
+            || self.terminated
+    }
+
+    /// Advances past the next field, returning its byte offset within `line` and its raw text
+    fn scan_field(&mut self) -> Option<(usize, &'a str)> {
+        if self.finished() {
+            return None;
+        }
+
+        let start = self.byte_pos;
+        self.field_pos += 1;
+
+        // A line of a single column is a common case that needs no scan at all
+        if let Some(field) = self.scan_only_field(start) {
+            self.byte_pos += field.len() + 1;
+
+            return Some((start, field));
+        }
+
+        #[cfg(feature = "memchr")]
+        if let Some((field, separator_length)) = self.scan_unquoted_field(start) {
+            self.byte_pos += field.len() + separator_length;
+            self.skip_collapsed_delimiters();
+
+            return Some((start, field));
+        }
+
+        if self.quote_mode == QuoteMode::Relaxed {
+            let (byte_length, separator_length) = self.scan_relaxed_field(start);
+            let field = &self.line[start..start + byte_length];
+            self.byte_pos += field.len() + separator_length;
+            self.skip_collapsed_delimiters();
+
+            return Some((start, field));
+        }
+
+        if !self.quoting {
+            let (byte_length, separator_length) = self.split_unquoted_field(start);
+            let field = &self.line[start..start + byte_length];
+            self.byte_pos += field.len() + separator_length;
+            self.skip_collapsed_delimiters();
+
+            return Some((start, field));
+        }
+
+        let charenum = self.line[start..self.fields_end()].char_indices();
+
+        let mut byte_length: usize = 0;
+        // The last field is followed by neither, so skipping a single byte moves past the end of the line
+        let mut separator_length: usize = 1;
+        let mut started = false;
+        let mut quoted = false;
+        // Within a quoted field, whether the last unescaped quote opened rather than closed the quotes
+        let mut in_quotes = false;
+        let mut escaping = false;
+        // The last char of this field that was neither padding nor escaped
+        let mut prev_char = None;
+
+        for (i, c) in charenum {
+            // Whitespace being trimmed or skipped neither opens nor closes a quoted field
+            let padding = self.trims() && self.is_trim_char(c)
+                || self.skip_space_before_quote && !started && c.is_ascii_whitespace();
+
+            let opening = self.quoting && !started && !padding && c == self.quote;
+
+            if !started && !padding {
+                started = true;
+                quoted = opening;
+            }
+
+            // A quoted field only ends at a delimiter right after the quote that closes it
+            if !quoted || !in_quotes && prev_char == Some(self.quote) {
+                if let Some(len) = self.terminator_len(&self.line[start + i..self.fields_end()]) {
+                    self.terminated = true;
+                    separator_length = len;
+                    break;
+                }
+
+                if let Some(len) = self
+                    .delimiter
+                    .prefix_len(&self.line[start + i..self.fields_end()])
+                {
+                    separator_length = len;
+                    break;
+                }
+            }
+
+            byte_length += c.len_utf8();
+
+            // A backslash-escaped character can never close a quoted field
+            if escaping {
+                escaping = false;
+                prev_char = None;
+            } else if !padding {
+                if quoted && c == self.quote {
+                    in_quotes = opening || !in_quotes;
+                }
+
+                escaping = self.escape_style.escape_char() == Some(c);
+                prev_char = Some(c);
+            }
+        }
+
+        // A lone quote that is never closed is a field of its own rather than the start of one that swallows the
+        // rest of the line. A record of a document may still be closed by text that has yet to be read, though.
+        if quoted && in_quotes && self.terminator.is_none() {
+            let region = &self.line[start..self.fields_end()];
+            let delimiter = region
+                .char_indices()
+                .find_map(|(i, _)| Some((i, self.delimiter.prefix_len(&region[i..])?)));
+
+            if let Some((i, len)) = delimiter {
+                if self.trimmed(&region[..i]).len() == self.quote.len_utf8() {
+                    byte_length = i;
+                    separator_length = len;
+                }
+            }
+        }
+
+        // Get the full field from start to finish, so that even an empty field points into `line`
+        let field = &self.line[start..start + byte_length];
+
+        self.byte_pos += field.len() + separator_length;
+        self.skip_collapsed_delimiters();
+
+        Some((start, field))
+    }
+
+    /// Advances past any delimiters that directly follow the one just scanned, when they are being collapsed
+    fn skip_collapsed_delimiters(&mut self) {
+        if !self.collapse_delimiters || self.terminated {
+            return;
+        }
+
+        while self.byte_pos < self.fields_end() {
+            match self
+                .delimiter
+                .prefix_len(&self.line[self.byte_pos..self.fields_end()])
+            {
+                Some(len) => self.byte_pos += len,
+                None => break,
+            }
+        }
+    }
+
+    /// Returns the whole line as its only field if it holds no delimiter, quote or terminator
+    ///
+    /// Returns `None` for any field but the first, so that a line of many fields is only searched once more.
+    fn scan_only_field(&self, start: usize) -> Option<&'a str> {
+        let Delimiter::Char(delimiter) = self.delimiter else {
+            return None;
+        };
+
+        if start != 0 || self.end != self.line.len() {
+            return None;
+        }
+
+        let line = &self.line[..self.line_len()];
+        let terminator = self.terminator.unwrap_or(delimiter);
+
+        #[cfg(feature = "memchr")]
+        if delimiter.is_ascii() && self.quote.is_ascii() && terminator.is_ascii() {
+            let found = memchr::memchr3(
+                delimiter as u8,
+                self.quote as u8,
+                terminator as u8,
+                line.as_bytes(),
+            );
+
+            return found.is_none().then_some(line);
+        }
+
+        (!line.contains([delimiter, self.quote, terminator])).then_some(line)
+    }
+
+    /// Finds the end of an unquoted field with memchr, returning the field and the length of the separator after it
+    ///
+    /// Returns `None` for a field that the char-by-char scan must handle instead: one that may be quoted, one with
+    /// leading whitespace to trim or skip, or one whose delimiter or terminator is not a single ASCII char.
+    #[cfg(feature = "memchr")]
+    fn scan_unquoted_field(&mut self, start: usize) -> Option<(&'a str, usize)> {
+        let region = &self.line[start..self.fields_end()];
+
+        let delimiter = match self.delimiter {
+            Delimiter::Char(d) if d.is_ascii() => d as u8,
+            _ => return None,
+        };
+
+        if self.escape_style != EscapeStyle::Doubled
+            || self.quoting && region.starts_with(self.quote)
+            || self.trims() && region.starts_with(|c: char| self.is_trim_char(c))
+            || self.skip_space_before_quote && region.starts_with(|c: char| c.is_ascii_whitespace())
+        {
+            return None;
+        }
+
+        let found = match self.terminator {
+            None => memchr::memchr(delimiter, region.as_bytes()),
+            Some(t) if t.is_ascii() => memchr::memchr2(delimiter, t as u8, region.as_bytes()),
+            Some(_) => return None,
+        };
+
+        let field = match found {
+            Some(i) => {
+                self.terminated = self.terminator == Some(region.as_bytes()[i] as char);
+                &region[..i]
+            }
+            None => region,
+        };
+
+        // A CR before a `\n` terminator belongs to the terminator rather than the field
+        if self.terminated && self.terminator == Some('\n') && field.ends_with('\r') {
+            return Some((&field[..field.len() - 1], 2));
+        }
+
+        // Both the delimiter and the terminator are a single byte, as is the step past the end of the line
+        Some((field, 1))
+    }
+
+    /// Finds the end of a field read with relaxed quotes, returning its length and the length of the separator after it
+    ///
+    /// This follows the states of the parser in Python's `csv` module, in which a delimiter ends a field anywhere but
+    /// within the quotes that open it.
+    fn scan_relaxed_field(&mut self, start: usize) -> (usize, usize) {
+        let region = &self.line[start..self.fields_end()];
+        let escape = self.escape_style.escape_char();
+        let mut state = RelaxedState::Start;
+        let mut escaping = false;
+
+        for (i, c) in region.char_indices() {
+            // An escaped char is part of the field whatever it is
+            if escaping {
+                escaping = false;
+
+                if state == RelaxedState::Start {
+                    state = RelaxedState::InField;
+                }
+                continue;
+            }
+
+            if state != RelaxedState::InQuotes {
+                if let Some(len) = self.terminator_len(&region[i..]) {
+                    self.terminated = true;
+                    return (i, len);
+                }
+
+                if let Some(len) = self.delimiter.prefix_len(&region[i..]) {
+                    return (i, len);
+                }
+            }
+
+            if escape == Some(c) && state != RelaxedState::QuoteInQuotes {
+                escaping = true;
+                continue;
+            }
+
+            let padding = self.trims() && self.is_trim_char(c)
+                || self.skip_space_before_quote && c.is_ascii_whitespace();
+
+            state = match state {
+                RelaxedState::Start if padding => RelaxedState::Start,
+                RelaxedState::Start if self.quoting && c == self.quote => RelaxedState::InQuotes,
+                RelaxedState::InQuotes if c == self.quote => match escape {
+                    None => RelaxedState::QuoteInQuotes,
+                    Some(_) => RelaxedState::InField,
+                },
+                RelaxedState::InQuotes => RelaxedState::InQuotes,
+                RelaxedState::QuoteInQuotes if c == self.quote => RelaxedState::InQuotes,
+                _ => RelaxedState::InField,
+            };
+        }
+
+        // The last field is followed by neither, so skipping a single byte moves past the end of the line
+        (region.len(), 1)
+    }
+
+    /// Finds the end of a field when quoting is off, returning its length and the length of the separator after it
+    ///
+    /// Without quotes, a field always ends at the first delimiter or terminator, so both are searched for directly
+    /// rather than checked for at every char.
+    fn split_unquoted_field(&mut self, start: usize) -> (usize, usize) {
+        let region = &self.line[start..self.fields_end()];
+
+        let terminator = self.terminator.and_then(|t| {
+            let i = region.find(t)?;
+
+            // A CR before a `\n` terminator belongs to the terminator rather than the field
+            match t == '\n' && region[..i].ends_with('\r') {
+                true => Some((i - 1, 2)),
+                false => Some((i, t.len_utf8())),
+            }
+        });
+        let delimiter = match terminator {
+            Some((i, _)) => self.delimiter.find_in(&region[..i]),
+            None => self.delimiter.find_in(region),
+        };
+
+        match (delimiter, terminator) {
+            (Some(found), _) => found,
+            (None, Some(found)) => {
+                self.terminated = true;
+                found
+            }
+            // The last field is followed by neither, so skipping a single byte moves past the end of the line
+            (None, None) => (region.len(), 1),
+        }
+    }
+
+    /// Returns the length of the record terminator at the start of `s`, if there is one
+    ///
+    /// A `\n` terminator also ends a record at a CRLF, so documents with either line ending split alike.
+    fn terminator_len(&self, s: &str) -> Option<usize> {
+        let terminator = self.terminator?;
+
+        match s.starts_with(terminator) {
+            true => Some(terminator.len_utf8()),
+            false if terminator == '\n' && s.starts_with("\r\n") => Some(2),
+            false => None,
+        }
+    }
+
+    /// Retreats past the last remaining field, returning its byte offset within `line` and its raw text
+    fn scan_field_back(&mut self) -> Option<(usize, &'a str)> {
+        if self.finished() {
+            return None;
+        }
+
+        if self.quote_mode == QuoteMode::Relaxed || self.delimiter.overlaps_itself() {
+            return self.scan_field_back_forwards();
+        }
+
+        let region = &self.line[self.byte_pos..self.fields_end()];
+
+        let Some(last_delimiter) = self.last_delimiter(region) else {
+            return self.scan_field_back_forwards();
+        };
+
+        match last_delimiter {
+            Some((delimiter, start)) => {
+                let start = self.byte_pos + start;
+                let field = &self.line[start..self.fields_end()];
+                self.end = self.byte_pos + delimiter;
+
+                // The rest of a run of delimiters separates the same two fields
+                while self.collapse_delimiters {
+                    match self
+                        .delimiter
+                        .suffix_len(&self.line[self.byte_pos..self.fields_end()])
+                    {
+                        Some(len) => self.end -= len,
+                        None => break,
+                    }
+                }
+
+                Some((start, field))
+            }
+            None => {
+                let start = self.byte_pos;
+                self.byte_pos = self.fields_end() + 1;
+
+                Some((start, region))
+            }
+        }
+    }
+
+    /// Retreats past the last remaining field by scanning the remaining fields forwards
+    ///
+    /// This is for a row whose last field cannot be told apart from the back, as with relaxed quotes.
+    fn scan_field_back_forwards(&mut self) -> Option<(usize, &'a str)> {
+        let mut row = self.clone();
+        let mut last = row.scan_field()?;
+        let mut prev_end = None;
+
+        while let Some(field) = row.scan_field() {
+            prev_end = Some(last.0 + last.1.len());
+            last = field;
+        }
+
+        match prev_end {
+            Some(end) => self.end = end,
+            None => self.byte_pos = self.fields_end() + 1,
+        }
+
+        Some(last)
+    }
+
+    /// Finds the delimiter before the last field of `region`, returning the offsets of the delimiter and the field
+    ///
+    /// Returns `Some(None)` if `region` is a single field, or `None` if only a scan from the front can tell where
+    /// the last field starts.
+    fn last_delimiter(&self, region: &str) -> Option<Option<(usize, usize)>> {
+        let body = match self.trims() {
+            true => region.trim_end_matches(|c: char| self.is_trim_char(c)),
+            false => region,
+        };
+
+        // A quoted field can hold delimiters, so look for the delimiter before its opening quote instead
+        if self.quoting
+            && body.len() > self.quote.len_utf8()
+            && body.ends_with(self.quote)
+            && !self.escaped_at(body, body.len() - self.quote.len_utf8())
+        {
+            let mut chars = body[..body.len() - self.quote.len_utf8()]
+                .char_indices()
+                .rev()
+                .peekable();
+
+            while let Some((i, c)) = chars.next() {
+                if c != self.quote || self.escaped_at(body, i) {
+                    continue;
+                }
+
+                if self.escape_style == EscapeStyle::Doubled
+                    && chars.next_if(|&(_, p)| p == self.quote).is_some()
+                {
+                    continue;
+                }
+
+                let before = region[..i].trim_end_matches(|c: char| {
+                    self.trims() && self.is_trim_char(c)
+                        || self.skip_space_before_quote && c.is_ascii_whitespace()
+                });
+
+                if before.is_empty() {
+                    return Some(None);
+                }
+
+                if let Some(len) = self.delimiter.suffix_len(before) {
+                    // Unless strict mode rules out stray quotes, this quote may instead close an earlier field, or
+                    // escape a quote within it, leaving a last field that is unquoted but ends with a quote. Either
+                    // way a delimiter or quote follows it, and a delimiter comes before the last quote.
+                    let contents =
+                        &body[i + self.quote.len_utf8()..body.len() - self.quote.len_utf8()];
+                    let after =
+                        contents.trim_start_matches(|c: char| self.trims() && self.is_trim_char(c));
+                    let may_close = self.delimiter.prefix_len(after).is_some()
+                        || self.escape_style == EscapeStyle::Doubled
+                            && after.starts_with(self.quote);
+
+                    if !self.strict
+                        && may_close
+                        && region[..i].contains(self.quote)
+                        && self.delimiter.find_in(contents).is_some()
+                    {
+                        return None;
+                    }
+
+                    return Some(Some((before.len() - len, before.len())));
+                }
+
+                // The field does not open with this quote, so it is not quoted after all
+                break;
+            }
+        }
+
+        Some(self.delimiter.rfind_in(region).map(|(d, len)| (d, d + len)))
+    }
+
+    /// Returns whether the character at byte offset `i` of `s` is escaped by the escape char
+    fn escaped_at(&self, s: &str, i: usize) -> bool {
+        match self.escape_style.escape_char() {
+            Some(escape) => s[..i].chars().rev().take_while(|&c| c == escape).count() % 2 == 1,
+            None => false,
+        }
+    }
+
+    /// Returns `field` with any trimmed whitespace removed
+    fn trimmed(&self, field: &'a str) -> &'a str {
+        let field = &field[self.padding_len(field)..];
+
+        match self.trims() {
+            true => field.trim_end_matches(|c: char| self.is_trim_char(c)),
+            false => field,
+        }
+    }
+
+    /// Returns the length in bytes of the leading whitespace to be removed from a raw field
+    fn padding_len(&self, field: &str) -> usize {
+        if self.trims() {
+            return field.len()
+                - field
+                    .trim_start_matches(|c: char| self.is_trim_char(c))
+                    .len();
+        }
+
+        let unpadded = field.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+        match self.skip_space_before_quote && unpadded.starts_with(self.quote) {
+            true => field.len() - unpadded.len(),
+            false => 0,
+        }
+    }
+
+    /// Returns the value of a raw field, trimmed and unescaped unless literal
+    fn unescape_field(&self, field: &'a str) -> Cow<'a, str> {
+        match self.mode {
+            FieldMode::Unescaped if self.quote_mode == QuoteMode::Relaxed => {
+                self.unescape_relaxed(field)
+            }
+            FieldMode::Unescaped => self.unescape(self.strip_quotes(field)),
+            FieldMode::KeepQuotes => self.requote_field(field),
+            FieldMode::StripQuotes => Cow::Borrowed(self.strip_quotes(field)),
+            FieldMode::Literal => Cow::Borrowed(field),
+        }
+    }
+
+    /// Returns a raw field trimmed and unescaped, but still enclosed in its quotes if it has them
+    fn requote_field(&self, field: &'a str) -> Cow<'a, str> {
+        let trimmed = self.trimmed(field);
+
+        if !self.is_quoted(trimmed) {
+            return self.unescape(trimmed);
+        }
+
+        let contents = self.strip_quotes(field);
+
+        match self.unescape(contents) {
+            Cow::Borrowed(c) if c.len() + 2 * self.quote.len_utf8() == trimmed.len() => {
+                Cow::Borrowed(trimmed)
+            }
+            c => Cow::Owned(format!("{q}{c}{q}", q = self.quote)),
+        }
+    }
+
+    /// Returns the value of a raw field read with relaxed quotes, as Python's `csv` module would read it
+    fn unescape_relaxed(&self, field: &'a str) -> Cow<'a, str> {
+        let field = match self.skip_space_before_quote {
+            true => field.trim_start_matches(|c: char| c.is_ascii_whitespace()),
+            false => field,
+        };
+        let field = self.trimmed(field);
+        let escape = self.escape_style.escape_char();
+
+        let value = match self.quoting && field.starts_with(self.quote) {
+            // Quotes within an unquoted field are like any other char
+            false if !escape.is_some_and(|e| field.contains(e)) => Cow::Borrowed(field),
+            false => Cow::Owned(unescape_relaxed_chars(
+                field,
+                escape,
+                RelaxedState::InField,
+                self.quote,
+            )),
+            true => {
+                let contents = &field[self.quote.len_utf8()..];
+                let inner = contents.strip_suffix(self.quote).unwrap_or(contents);
+
+                match inner.contains(self.quote) || escape.is_some_and(|e| inner.contains(e)) {
+                    false => Cow::Borrowed(inner),
+                    true => Cow::Owned(unescape_relaxed_chars(
+                        contents,
+                        escape,
+                        RelaxedState::InQuotes,
+                        self.quote,
+                    )),
+                }
+            }
+        };
+
+        match (self.trim_mode, value) {
+            (TrimMode::All, Cow::Borrowed(value)) => {
+                Cow::Borrowed(value.trim_matches(|c: char| self.is_trim_char(c)))
+            }
+            (TrimMode::All, Cow::Owned(value)) => {
+                Cow::Owned(value.trim_matches(|c: char| self.is_trim_char(c)).into())
+            }
+            (_, value) => value,
+        }
+    }
+
+    /// Returns `field` with its escaped quotes unescaped
+    fn unescape(&self, field: &'a str) -> Cow<'a, str> {
+        match self.escape_style.escape_char() {
+            None => collapse_quotes(field, self.quote),
+            Some(escape) => unescape_escaped(field, self.quote, escape),
+        }
+    }
+
+    /// Returns the value of a raw field as `unescape_field` would, writing it to `scratch` only if it needs unescaping
+    fn unescape_field_into<'s>(&self, field: &'a str, scratch: &'s mut String) -> &'s str
+    where
+        'a: 's,
+    {
+        let result = match self.mode {
+            FieldMode::Unescaped if self.quote_mode == QuoteMode::Relaxed => {
+                return copy_into(self.unescape_relaxed(field), scratch);
+            }
+            FieldMode::Unescaped => self.strip_quotes(field),
+            FieldMode::KeepQuotes => return copy_into(self.requote_field(field), scratch),
+            FieldMode::StripQuotes => return self.strip_quotes(field),
+            FieldMode::Literal => return field,
+        };
+        scratch.clear();
+
+        let unescaped = match self.escape_style.escape_char() {
+            None => collapse_quotes_into(result, self.quote, scratch),
+            Some(escape) => unescape_escaped_into(result, self.quote, escape, scratch),
+        };
+
+        match unescaped {
+            true => scratch,
+            false => result,
+        }
+    }
+
+    /// Returns whether a trimmed raw field is enclosed in quotes
+    fn is_quoted(&self, field: &str) -> bool {
+        self.quoting
+            && field.len() > self.quote.len_utf8()
+            && field.starts_with(self.quote)
+            && field.ends_with(self.quote)
+            && !self.escaped_at(field, field.len() - self.quote.len_utf8())
+    }
+
+    /// Returns a raw field trimmed and without its enclosing quotes, if it has them
+    fn strip_quotes(&self, field: &'a str) -> &'a str {
+        let result = self.trimmed(field);
+
+        if self.is_quoted(result) {
+            let contents = &result[self.quote.len_utf8()..result.len() - self.quote.len_utf8()];
+
+            return match self.trim_mode {
+                TrimMode::All => contents.trim_matches(|c: char| self.is_trim_char(c)),
+                _ => contents,
+            };
+        }
+
+        result
+    }
+
+    /// Checks that a field starting at byte offset `start` of `line` is either unquoted or properly quoted
+    fn check_quotes(&self, start: usize, field: &'a str) -> Result<(), CsvError> {
+        let result = self.trimmed(field);
+        let start = start + self.padding_len(field);
+
+        // Without quoting, a quote is like any other char, and relaxed quotes accept any field
+        if !self.quoting || self.quote_mode == QuoteMode::Relaxed {
+            return Ok(());
+        }
+
+        if !result.starts_with(self.quote) {
+            let stray = result
+                .match_indices(self.quote)
+                .map(|(i, _)| i)
+                .find(|&i| !self.escaped_at(result, i));
+
+            return match stray {
+                Some(i) if self.strict => Err(CsvError::MalformedField {
+                    byte_pos: start + i,
+                }),
+                _ => Ok(()),
+            };
+        }
+
+        let mut chars = result.char_indices().skip(1).peekable();
+
+        while let Some((i, c)) = chars.next() {
+            if self.escape_style.escape_char() == Some(c) {
+                chars.next();
+            } else if c == self.quote {
+                if chars.peek().is_none() {
+                    return Ok(());
+                }
+
+                if self.escape_style != EscapeStyle::Doubled
+                    || chars.next_if(|&(_, n)| n == self.quote).is_none()
+                {
+                    // Strict mode reads the quote as closing the field, so whatever follows it is out of place
+                    return Err(match self.strict {
+                        true => CsvError::TrailingDataAfterQuote {
+                            byte_pos: start + i + c.len_utf8(),
+                        },
+                        false => CsvError::UnexpectedQuote {
+                            byte_pos: start + i,
+                        },
+                    });
+                }
+            }
+        }
+
+        Err(CsvError::UnterminatedQuote { byte_pos: start })
+    }
+}
+
+/// Shows the options, the cursor and a preview of `line` truncated to its first 32 chars
+impl fmt::Debug for CsvRow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let byte_pos = self.byte_pos.min(self.line.len());
+        let line = match self.line.char_indices().nth(DEBUG_PREVIEW_LEN) {
+            Some((i, _)) => format!("{:?}...", &self.line[..i]),
+            None => format!("{:?}", self.line),
+        };
+        f.debug_struct("CsvRow")
+            .field("line", &format_args!("{line}"))
+            .field("delimiter", &self.delimiter)
+            .field("mode", &self.mode)
+            .field("quote", &self.quote)
+            .field("byte_pos", &byte_pos)
+            .field("char_pos", &self.line[..byte_pos].chars().count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Iterator for CsvRow<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, field) = self.scan_field()?;
+        self.raw = Some(field);
+
+        Some(self.unescape_field(field))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished() {
+            return (0, Some(0));
+        }
+
+        // Every remaining field but the last ends at a delimiter, though some may be quoted
+        let delimiters = self
+            .delimiter
+            .count_in(&self.line[self.byte_pos..self.fields_end()]);
+
+        (1, Some(delimiters + 1))
+    }
+
+    fn count(mut self) -> usize {
+        // Scanning finds every field without unescaping any of them
+        let mut count = 0;
+
+        while self.scan_field().is_some() {
+            count += 1;
+        }
+
+        count
+    }
+}
+
+/// Fields are parsed from the back by searching for the delimiter before each one, or for a quoted field, the
+/// delimiter before its opening quote. Where that search is ambiguous, the remaining fields are scanned from the
+/// front instead: with relaxed quotes, with a `Str` delimiter whose occurrences can overlap, or when, outside of
+/// strict mode, the opening quote of a last field holding delimiters may be the closing quote of an earlier one.
+/// This yields the same fields as `next()` for any row that `try_fields` accepts, but a malformed row may be split
+/// differently from each end.
+impl<'a> DoubleEndedIterator for CsvRow<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, field) = self.scan_field_back()?;
+        self.raw = Some(field);
+
+        Some(self.unescape_field(field))
+    }
+}
+
+/// Once every field has been parsed, a CsvRow only ever returns `None`.
+impl FusedIterator for CsvRow<'_> {}
+
+/// An iterator over the fields of a CsvRow that yields a CsvError for each malformed field
+///
+/// Created by `CsvRow::try_fields`.
+pub struct TryFields<'a> {
+    row: CsvRow<'a>,
+}
+
+impl<'a> Iterator for TryFields<'a> {
+    type Item = Result<Cow<'a, str>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, field) = self.row.scan_field()?;
+
+        if self.row.max_field_len.is_some_and(|max| field.len() > max) {
+            return Some(Err(CsvError::FieldTooLong { byte_pos: start }));
+        }
+
+        if let Some(i) = field.find('\0').filter(|_| self.row.reject_nul) {
+            return Some(Err(CsvError::NulByte {
+                byte_pos: start + i,
+            }));
+        }
+
+        Some(
+            self.row
+                .check_quotes(start, field)
+                .map(|()| self.row.unescape_field(field)),
+        )
+    }
+}
+
+impl FusedIterator for TryFields<'_> {}
+
+/// An iterator over the fields of a CsvRow that tells bare empty fields apart from empty quoted ones
+///
+/// Created by `CsvRow::nullable`.
+pub struct Nullable<'a> {
+    row: CsvRow<'a>,
+}
+
+impl<'a> Nullable<'a> {
+    /// Classifies a raw field as `CsvRow::nullable` describes
+    fn classify(&self, field: &'a str) -> Field<'a> {
+        if self.row.trimmed(field).is_empty() {
+            return Field::Null;
+        }
+
+        match self.row.unescape_field(field) {
+            value if value.is_empty() => Field::Empty,
+            value => Field::Value(value),
+        }
+    }
+}
+
+impl<'a> Iterator for Nullable<'a> {
+    type Item = Field<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, field) = self.row.scan_field()?;
+
+        Some(self.classify(field))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Nullable<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, field) = self.row.scan_field_back()?;
+
+        Some(self.classify(field))
+    }
+}
+
+impl FusedIterator for Nullable<'_> {}
+
+/// An iterator over the fields of a CsvRow that parses each field into a `T`
+///
+/// Created by `CsvRow::parse_fields`.
+pub struct ParseFields<'a, T> {
+    row: CsvRow<'a>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromStr> Iterator for ParseFields<'_, T> {
+    type Item = Result<T, T::Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.row.next().map(|field| field.parse())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl<T: FromStr> DoubleEndedIterator for ParseFields<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.row.next_back().map(|field| field.parse())
+    }
+}
+
+impl<T: FromStr> FusedIterator for ParseFields<'_, T> {}
+
+/// An iterator over the fields of a CsvRow that parses each one as an `f64` with a custom decimal separator
+///
+/// Created by `CsvRow::parse_decimals`.
+pub struct ParseDecimals<'a> {
+    row: CsvRow<'a>,
+    decimal: char,
+}
+
+impl ParseDecimals<'_> {
+    /// Parses an unescaped field after replacing its decimal separator with `.`
+    fn parse(&self, field: Cow<'_, str>) -> Result<f64, ParseFloatError> {
+        match self.decimal {
+            '.' => field.parse(),
+            decimal => field.replace(decimal, ".").parse(),
+        }
+    }
+}
+
+impl Iterator for ParseDecimals<'_> {
+    type Item = Result<f64, ParseFloatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = self.row.next()?;
+
+        Some(self.parse(field))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ParseDecimals<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let field = self.row.next_back()?;
+
+        Some(self.parse(field))
+    }
+}
+
+impl FusedIterator for ParseDecimals<'_> {}
+
+/// An iterator over the fields of a CsvRow that yields the byte range of each field alongside its value
+///
+/// Created by `CsvRow::spanned`.
+pub struct Spanned<'a> {
+    row: CsvRow<'a>,
+}
+
+impl<'a> Iterator for Spanned<'a> {
+    type Item = (Range<usize>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, field) = self.row.scan_field()?;
+
+        Some((start..start + field.len(), self.row.unescape_field(field)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Spanned<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (start, field) = self.row.scan_field_back()?;
+
+        Some((start..start + field.len(), self.row.unescape_field(field)))
+    }
+}
+
+impl FusedIterator for Spanned<'_> {}
+
+/// An iterator over the fields of a CsvRow that yields whether each field was quoted alongside its value
+///
+/// Created by `CsvRow::quoted_fields`.
+pub struct QuotedFields<'a> {
+    row: CsvRow<'a>,
+}
+
+impl<'a> Iterator for QuotedFields<'a> {
+    type Item = (bool, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, field) = self.row.scan_field()?;
+
+        Some((
+            self.row.is_quoted(self.row.trimmed(field)),
+            self.row.unescape_field(field),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for QuotedFields<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, field) = self.row.scan_field_back()?;
+
+        Some((
+            self.row.is_quoted(self.row.trimmed(field)),
+            self.row.unescape_field(field),
+        ))
+    }
+}
+
+impl FusedIterator for QuotedFields<'_> {}
+
+/// An iterator over the fields of a CsvRow that maps each one with a closure
+///
+/// Created by `CsvRow::map_fields`.
+pub struct MapFields<'a, F> {
+    row: CsvRow<'a>,
+    f: F,
+}
+
+impl<'a, F: FnMut(Cow<'a, str>) -> Cow<'a, str>> Iterator for MapFields<'a, F> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.row.next().map(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl<'a, F: FnMut(Cow<'a, str>) -> Cow<'a, str>> DoubleEndedIterator for MapFields<'a, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.row.next_back().map(&mut self.f)
+    }
+}
+
+impl<'a, F: FnMut(Cow<'a, str>) -> Cow<'a, str>> FusedIterator for MapFields<'a, F> {}
+
+/// An iterator over the overlapping pairs of consecutive fields of a CsvRow
+///
+/// Created by `CsvRow::pairs`.
+pub struct Pairs<'a> {
+    row: CsvRow<'a>,
+    prev: Option<Cow<'a, str>>,
+}
+
+impl<'a> Iterator for Pairs<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.prev.take() {
+            Some(field) => field,
+            None => self.row.next()?,
+        };
+        let second = self.row.next()?;
+        self.prev = Some(second.clone());
+
+        Some((first, second))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The field kept from the last pair starts the next one
+        let (lower, upper) = self.row.size_hint();
+        let kept = usize::from(self.prev.is_some());
+
+        (
+            (lower + kept).saturating_sub(1),
+            upper.map(|upper| (upper + kept).saturating_sub(1)),
+        )
+    }
+}
+
+impl FusedIterator for Pairs<'_> {}
+
+/// Where the relaxed scan of a field is, after the states of the parser in Python's `csv` module
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RelaxedState {
+    /// No char of the field but padding has been read
+    Start,
+    /// Within an unquoted field, or after the quotes of a quoted one
+    InField,
+    /// Within the quotes of a quoted field
+    InQuotes,
+    /// Right after a quote within the quotes of a quoted field, which either escapes another or closes them
+    QuoteInQuotes,
+}
+
+/// Returns `field` unescaped by the relaxed rules, starting in `state`
+///
+/// `field` starts after the opening quote of a quoted field. An escape char is dropped and the char it escapes kept,
+/// and a quote that closes the quotes is dropped while the text after it is kept.
+fn unescape_relaxed_chars(
+    field: &str,
+    escape: Option<char>,
+    mut state: RelaxedState,
+    quote: char,
+) -> String {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        if escape == Some(c) && state != RelaxedState::QuoteInQuotes {
+            // A trailing escape char has nothing to escape, so it is kept
+            unescaped.push(chars.next().unwrap_or(c));
+            continue;
+        }
+
+        state = match state {
+            RelaxedState::InQuotes if c == quote => match escape {
+                None => RelaxedState::QuoteInQuotes,
+                Some(_) => RelaxedState::InField,
+            },
+            RelaxedState::QuoteInQuotes if c == quote => {
+                unescaped.push(c);
+                RelaxedState::InQuotes
+            }
+            RelaxedState::InQuotes => {
+                unescaped.push(c);
+                RelaxedState::InQuotes
+            }
+            _ => {
+                unescaped.push(c);
+                RelaxedState::InField
+            }
+        };
+    }
+
+    unescaped
+}
+
+/// Returns `value` borrowed as it is, or copied into `scratch` if it is owned
+fn copy_into<'s>(value: Cow<'s, str>, scratch: &'s mut String) -> &'s str {
+    match value {
+        Cow::Borrowed(value) => value,
+        Cow::Owned(value) => {
+            scratch.clear();
+            scratch.push_str(&value);
+            scratch
+        }
+    }
+}
+
+/// Collapses each doubled `quote` (the RFC-4180 escape sequence) in `field` into a single `quote`.
+fn collapse_quotes(field: &str, quote: char) -> Cow<'_, str> {
+    let mut unescaped = String::new();
+
+    match collapse_quotes_into(field, quote, &mut unescaped) {
+        true => Cow::Owned(unescaped),
+        false => Cow::Borrowed(field),
+    }
+}
+
+/// Appends `field` to `unescaped` with doubled quotes collapsed, returning false without appending if there are none
+fn collapse_quotes_into(field: &str, quote: char, unescaped: &mut String) -> bool {
+    let mut copied = 0;
+    let mut chars = field.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == quote && chars.next_if(|&(_, n)| n == quote).is_some() {
+            unescaped.push_str(&field[copied..i + c.len_utf8()]);
+            copied = i + c.len_utf8() * 2;
+        }
+    }
+
+    if copied > 0 {
+        unescaped.push_str(&field[copied..]);
+    }
+
+    copied > 0
+}
+
+/// Replaces each escaped quote or escape char in `field` with the character itself.
+fn unescape_escaped(field: &str, quote: char, escape: char) -> Cow<'_, str> {
+    let mut unescaped = String::new();
+
+    match unescape_escaped_into(field, quote, escape, &mut unescaped) {
+        true => Cow::Owned(unescaped),
+        false => Cow::Borrowed(field),
+    }
+}
+
+/// Appends `field` to `unescaped` with escape chars removed, returning false without appending if there are none
+fn unescape_escaped_into(field: &str, quote: char, escape: char, unescaped: &mut String) -> bool {
+    let mut copied = 0;
+    let mut chars = field.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c == escape {
+            if let Some((j, e)) = chars.next() {
+                if e == quote || e == escape {
+                    unescaped.push_str(&field[copied..i]);
+                    copied = j;
+                }
+            }
+        }
+    }
+
+    if copied > 0 {
+        unescaped.push_str(&field[copied..]);
+    }
+
+    copied > 0
+}
+
+/// Returns `Cow::Owned<str> if `expression` requires escaping to be RFC-4180 compliant, that is
+/// if it contains the delimiter, a quote, or a line break.
+///
+/// Returns `Cow::Borrowed<str>` referencing `expression` if it does not.
+///
+/// # Arguments
+///
+/// * `expression` - A string slice that holds the value to escape
+/// * `delimiter` - A char that represents the delimiter used in the CSV document
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::escape;
+/// let expression = "chupacabra";
+/// let result = escape(expression, ',');
+///
+/// assert_eq!(expression, result);
+///
+/// let expression = "this is a \"test\", of course...";
+/// let result = escape(expression, ',');
+///
+/// assert_eq!("\"this is a \"\"test\"\", of course...\"", result)
+/// ```
+pub fn escape(expression: &str, delimiter: char) -> Cow<'_, str> {
+    match needs_quotes(expression, delimiter) {
+        true => {
+            let mut escaped = String::with_capacity(expression.len() + 2);
+            escape_into(expression, delimiter, &mut escaped);
+            Cow::Owned(escaped)
+        }
+        false => Cow::Borrowed(expression),
+    }
+}
+
+/// Returns `expression` escaped as `escape()` would escape it, but also quoted if it contains `terminator`.
+///
+/// A field holding the record terminator must be quoted for the record to be read back whole, so this keeps a
+/// field self-consistent with a document whose records end with a terminator other than a line break, such as
+/// the one set with `CsvDocument::terminator`.
+///
+/// # Arguments
+///
+/// * `expression` - A string slice that holds the value to escape
+/// * `delimiter` - A char that represents the delimiter used in the CSV document
+/// * `terminator` - A char that represents the end of a record in the CSV document
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{escape, escape_with_terminator};
+///
+/// assert_eq!("a\u{1E}b", escape("a\u{1E}b", ','));
+/// assert_eq!("\"a\u{1E}b\"", escape_with_terminator("a\u{1E}b", ',', '\u{1E}'))
+/// ```
+pub fn escape_with_terminator(expression: &str, delimiter: char, terminator: char) -> Cow<'_, str> {
+    match needs_quotes_with(expression, delimiter, terminator) {
+        true => {
+            let mut escaped = String::with_capacity(expression.len() + 2);
+            quote_into(expression, &mut escaped);
+            Cow::Owned(escaped)
+        }
+        false => Cow::Borrowed(expression),
+    }
+}
+
+/// Returns `expression` escaped as `escape()` would, along with whether it had to be quoted
+///
+/// # Arguments
+///
+/// * `expression` - A string slice that holds the value to escape
+/// * `delimiter` - A char that represents the delimiter used in the CSV document
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::escape_reported;
+/// let (result, quoted) = escape_reported("a,b", ',');
+///
+/// assert_eq!(("\"a,b\"", true), (&*result, quoted))
+/// ```
+pub fn escape_reported(expression: &str, delimiter: char) -> (Cow<'_, str>, bool) {
+    let escaped = escape(expression, delimiter);
+    let quoted = matches!(escaped, Cow::Owned(_));
+
+    (escaped, quoted)
+}
+
+/// Returns `expression` escaped as `escape()` would escape it, but escaping quotes with `escape_style`.
+///
+/// With `EscapeStyle::Backslash`, a quote is escaped as `\"` and a backslash as `\\`, so that a CsvRow with the same
+/// escape style reads the value back unchanged. `EscapeStyle::Char` escapes with its own char in the same way. Returns
+/// `Cow::Borrowed<str>` referencing `expression` if nothing needs escaping.
+///
+/// # Arguments
+///
+/// * `expression` - A string slice that holds the value to escape
+/// * `delimiter` - A char that represents the delimiter used in the CSV document
+/// * `escape_style` - An EscapeStyle that represents how quotes are escaped
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{escape_with_style, EscapeStyle};
+/// let expression = "this is a \"test\", of course...";
+/// let result = escape_with_style(expression, ',', EscapeStyle::Backslash);
+///
+/// assert_eq!(r#""this is a \"test\", of course...""#, result)
+/// ```
+pub fn escape_with_style(
+    expression: &str,
+    delimiter: char,
+    escape_style: EscapeStyle,
+) -> Cow<'_, str> {
+    let escape_char = match escape_style.escape_char() {
+        Some(escape_char) => escape_char,
+        None => return escape(expression, delimiter),
+    };
+
+    let quoted = needs_quotes(expression, delimiter);
+
+    if !quoted && !expression.contains(escape_char) {
+        return Cow::Borrowed(expression);
+    }
+
+    let mut escaped = String::with_capacity(expression.len() + 2);
+
+    if quoted {
+        escaped.push('"');
+    }
+
+    for c in expression.chars() {
+        if c == '"' || c == escape_char {
+            escaped.push(escape_char);
+        }
+
+        escaped.push(c);
+    }
+
+    if quoted {
+        escaped.push('"');
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Appends `expression` to `out`, escaped as `escape()` would escape it.
+///
+/// Reusing `out` across many fields avoids allocating a new `String` for each field that needs quoting.
+///
+/// # Arguments
+///
+/// * `expression` - A string slice that holds the value to escape
+/// * `delimiter` - A char that represents the delimiter used in the CSV document
+/// * `out` - The String to append the escaped value to
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::escape_into;
+/// let mut line = String::new();
+/// escape_into("chupacabra", ',', &mut line);
+/// line.push(',');
+/// escape_into("of course, \"test\"", ',', &mut line);
+///
+/// assert_eq!("chupacabra,\"of course, \"\"test\"\"\"", line)
+/// ```
+pub fn escape_into(expression: &str, delimiter: char, out: &mut String) {
+    if !needs_quotes(expression, delimiter) {
+        out.push_str(expression);
+        return;
+    }
+
+    quote_into(expression, out);
+}
+
+/// Returns `expression` enclosed in quotes, with its quotes doubled, whether or not it requires escaping.
+///
+/// Some consumers, such as database bulk loaders, expect every field to be quoted. Since the result is quoted
+/// regardless of its content, no delimiter is needed.
+///
+/// # Arguments
+///
+/// * `expression` - A string slice that holds the value to escape
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::escape_quoted;
+/// let result = escape_quoted("chupacabra");
+///
+/// assert_eq!("\"chupacabra\"", result)
+/// ```
+pub fn escape_quoted(expression: &str) -> String {
+    let mut escaped = String::with_capacity(expression.len() + 2);
+    quote_into(expression, &mut escaped);
+    escaped
+}
+
+/// Appends `expression` to `out` enclosed in quotes, with its quotes doubled
+fn quote_into(expression: &str, out: &mut String) {
+    out.push('"');
+
+    for (i, part) in expression.split('"').enumerate() {
+        if i > 0 {
+            out.push_str("\"\"");
+        }
+
+        out.push_str(part);
+    }
+
+    out.push('"');
+}
+
+/// Returns whether `expression` must be quoted to be RFC-4180 compliant
+fn needs_quotes(expression: &str, delimiter: char) -> bool {
+    needs_quotes_with(expression, delimiter, '\n')
+}
+
+/// Returns whether `expression` must be quoted in a document whose records end with `terminator`
+fn needs_quotes_with(expression: &str, delimiter: char, terminator: char) -> bool {
+    expression.contains([delimiter, '"', '\n', '\r', terminator])
+}
+
+/// Returns the candidate that splits `line` into the most fields, respecting quoted fields.
+///
+/// Ties go to the candidate listed first. Returns `None` if no candidate yields more than one field.
+///
+/// # Arguments
+///
+/// * `line` - A string slice that holds a sample row
+/// * `candidates` - The delimiters to try, commonly `,`, `;`, `\t` and `|`
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::detect_delimiter;
+/// let line = "a;\"b,c\";d";
+///
+/// assert_eq!(Some(';'), detect_delimiter(line, &[',', ';', '\t', '|']))
+/// ```
+pub fn detect_delimiter(line: &str, candidates: &[char]) -> Option<char> {
+    let mut best = None;
+    let mut best_count = 1;
+
+    for &candidate in candidates {
+        let count = CsvRow::field_count(line, candidate);
+
+        if count > best_count {
+            best = Some(candidate);
+            best_count = count;
+        }
+    }
+
+    best
+}
+
+/// Splits `line` on the candidate that `detect_delimiter()` chooses for it, returning that delimiter and the
+/// unescaped fields.
+///
+/// The first candidate is used if none yields more than one field, so each line of a mixed input can be parsed
+/// independently.
+///
+/// # Arguments
+///
+/// * `line` - A string slice that holds the delimited fields
+/// * `candidates` - The delimiters to try, commonly `,`, `;`, `\t` and `|`
+///
+/// # Panics
+///
+/// Panics if `candidates` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::parse_autodetect;
+/// let (delimiter, fields) = parse_autodetect("a\t\"b,c\"\td", &[',', '\t']);
+///
+/// assert_eq!('\t', delimiter);
+/// assert_eq!(vec!["a", "b,c", "d"], fields)
+/// ```
+pub fn parse_autodetect<'a>(line: &'a str, candidates: &[char]) -> (char, Vec<Cow<'a, str>>) {
+    assert!(!candidates.is_empty(), "candidates must not be empty");
+
+    let delimiter = detect_delimiter(line, candidates).unwrap_or(candidates[0]);
+
+    (delimiter, CsvRow::new(line, delimiter, false).collect())
+}
+
+/// Returns the zero-based position of the column named `name` in `header_line`, comparing names exactly
+///
+/// Each header is unescaped before it is compared, so a quoted header matches its unquoted name. If two columns
+/// share a name, the first is returned. Combined with `CsvRow::field`, this pulls a single column from each row.
+///
+/// # Arguments
+///
+/// * `header_line` - A string slice that holds the delimited column names
+/// * `name` - The column name to look for
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{header_index, CsvRow};
+/// let index = header_index("name,\"city, country\"", "city, country", ',').unwrap();
+///
+/// assert_eq!(Some("London".into()), CsvRow::field("Ada,London", ',', index, false))
+/// ```
+pub fn header_index(header_line: &str, name: &str, delimiter: char) -> Option<usize> {
+    CsvRow::new(header_line, delimiter, false).position(|header| header == name)
+}
+
+/// Returns the zero-based position of the column named `name` in `header_line`, ignoring case
+///
+/// This is `header_index()`, except that names are compared by their lowercase forms.
+///
+/// # Arguments
+///
+/// * `header_line` - A string slice that holds the delimited column names
+/// * `name` - The column name to look for
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::header_index_ignore_case;
+///
+/// assert_eq!(Some(1), header_index_ignore_case("Name,City", "CITY", ','))
+/// ```
+pub fn header_index_ignore_case(header_line: &str, name: &str, delimiter: char) -> Option<usize> {
+    let lowercase = |s: &str| s.chars().flat_map(char::to_lowercase).collect::<String>();
+    let name = lowercase(name);
+
+    CsvRow::new(header_line, delimiter, false).position(|header| lowercase(&header) == name)
+}
+
+/// Returns an iterator over the column names of `header_line`, each trimmed and lowercased, with its position
+///
+/// Whitespace is trimmed inside quotes as well, and names are lowercased as `header_index_ignore_case()` compares
+/// them, so a lookup key written in lowercase finds its column regardless of the casing or padding of the header.
+///
+/// # Arguments
+///
+/// * `header_line` - A string slice that holds the delimited column names
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::normalized_headers;
+/// let header = r#"ID, " First Name " ,City"#;
+/// let index = normalized_headers(header, ',').find(|(_, key)| key == "first name").map(|(i, _)| i);
+///
+/// assert_eq!(Some(1), index)
+/// ```
+pub fn normalized_headers(header_line: &str, delimiter: char) -> NormalizedHeaders<'_> {
+    NormalizedHeaders {
+        row: CsvRow::new(header_line, delimiter, false).trim_mode(TrimMode::All),
+        index: 0,
+    }
+}
+
+/// An iterator over the trimmed and lowercased column names of a header line, along with their positions
+///
+/// Created by `normalized_headers`.
+pub struct NormalizedHeaders<'a> {
+    row: CsvRow<'a>,
+    index: usize,
+}
+
+impl Iterator for NormalizedHeaders<'_> {
+    type Item = (usize, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.row.next()?;
+        let index = self.index;
+        self.index += 1;
+
+        Some((index, header.chars().flat_map(char::to_lowercase).collect()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.row.size_hint()
+    }
+}
+
+impl FusedIterator for NormalizedHeaders<'_> {}
+
+/// Returns the value of a single field, with enclosing quotes removed and escaped quotes unescaped, exactly as
+/// `CsvRow` would yield it in non-literal mode. This is the inverse of `escape()`.
+///
+/// Returns `Cow::Borrowed<str>` referencing `field` if there is nothing to unescape. The field is not split
+/// on `delimiter`.
+///
+/// # Arguments
+///
+/// * `field` - A string slice that holds the value to unescape
+/// * `delimiter` - A char that represents the delimiter used in the CSV document
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::unescape;
+/// let field = "\"this is a \"\"test\"\", of course...\"";
+/// let result = unescape(field, ',');
+///
+/// assert_eq!("this is a \"test\", of course...", result)
+/// ```
+pub fn unescape(field: &str, delimiter: char) -> Cow<'_, str> {
+    CsvRow::new(field, delimiter, false).unescape_field(field)
+}
+
+/// Returns a CsvRow over the sub-fields of an already unescaped field, such as the list `a;b;c` within a cell
+///
+/// The sub-fields are split with the same quote-aware parsing as `CsvRow::new`, so a sub-field may itself be
+/// quoted to hold `sub_delimiter`.
+///
+/// # Arguments
+///
+/// * `field` - A string slice that holds the value of a field
+/// * `sub_delimiter` - A char that represents the delimiter between the sub-fields
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{subfields, CsvRow};
+/// let field = CsvRow::new(r#"x,"a;b""#, ',', false).nth(1).unwrap();
+/// let vec_t: Vec<_> = vec!["a", "b"];
+/// let vec_r: Vec<_> = subfields(&field, ';').collect();
+///
+/// assert_eq!(vec_t[..], vec_r[..])
+/// ```
+pub fn subfields(field: &str, sub_delimiter: char) -> CsvRow<'_> {
+    CsvRow::new(field, sub_delimiter, false)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn can_parse_tiny_csv() {
+        let row = "a,b,c,d";
+        let csv = CsvRow::new(row, ',', false);
+
+        // for field in csv {
+        //     println!("{field:?}");
+        // }
+
+        let vec_t: Vec<_> = vec!["a", "b", "c", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_empty_row() {
+        let row = "";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<Cow<'_, str>> = vec![];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_simple_csv() {
+        let row = r#"january,february,march,april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "february", "march", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_tiny_csv_with_non_ascii() {
+        let row = r#"è,b,c,d"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["è", "b", "c", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_one_field_csv() {
+        let row = "january";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn blank_field_mid_string() {
+        let row = "january,,april";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trailing_comma_yields_empty_string() {
+        let row = "january,";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", ""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_simple_csv_with_quoted_field() {
+        let row = r#"january,february,"leap day",march,april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "february", "leap day", "march", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_quoted_field_containing_delim() {
+        let row = r#"january,february,"leap day, the",march,april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "february", "leap day, the", "march", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_quoted_field_containing_quote() {
+        let row = r#"january,february,"The ""Coder"" Man",march,april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "february", "The \"Coder\" Man", "march", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_orphaned_quote() {
+        let row = r#"january,feb"ruary,march,april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "feb\"ruary", "march", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_premature_close_quote() {
+        let row = r#"january,"feb"ruary,march,april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", "\"feb\"ruary,march,april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_empty_quoted_field() {
+        let row = r#"january,"""#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", ""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_simple_csv_with_spaces() {
+        let row = r#"january, "february", march, april"#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["january", " \"february\"", " march", " april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn escapes_complex_string() {
+        let expression = "this is a \"test\", of course...";
+        let result = escape(expression, ',');
+
+        assert_eq!("\"this is a \"\"test\"\", of course...\"", result)
+    }
+
+    #[test]
+    fn does_not_escape_simple_string() {
+        let expression = "chupacabra";
+        let result = escape(expression, ',');
+
+        assert_eq!(expression, result)
+    }
+
+    #[test]
+    fn escape_reported_reports_simple_string_unquoted() {
+        let (result, quoted) = escape_reported("chupacabra", ',');
+
+        assert_eq!("chupacabra", result);
+        assert!(!quoted)
+    }
+
+    #[test]
+    fn escape_reported_reports_comma_quoted() {
+        let (result, quoted) = escape_reported("chupa,cabra", ',');
+
+        assert_eq!("\"chupa,cabra\"", result);
+        assert!(quoted)
+    }
+
+    #[test]
+    fn can_parse_csv_with_single_quote_char() {
+        let row = "a,'b,c',d";
+
+        let csv = CsvRow::with_quote(row, ',', false, '\'');
+
+        let vec_t: Vec<_> = vec!["a", "b,c", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_doubled_single_quote_char() {
+        let row = "a,'it''s',d";
+
+        let csv = CsvRow::with_quote(row, ',', false, '\'');
+
+        let vec_t: Vec<_> = vec!["a", "it's", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn custom_quote_char_ignores_double_quotes() {
+        let row = r#"a,"b,c",d"#;
+
+        let csv = CsvRow::with_quote(row, ',', false, '\'');
+
+        let vec_t: Vec<_> = vec!["a", "\"b", "c\"", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_literal_csv_with_single_quote_char() {
+        let row = "a,'b,c',d";
+
+        let csv = CsvRow::with_quote(row, ',', true, '\'');
+
+        let vec_t: Vec<_> = vec!["a", "'b,c'", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_backslash_escaped_quote() {
+        let row = r#""a\"b""#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+
+        let vec_t: Vec<_> = vec!["a\"b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_backslash_escaped_quote_before_delim() {
+        let row = r#"january,"feb\",ruary",march"#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+
+        let vec_t: Vec<_> = vec!["january", "feb\",ruary", "march"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_backslash_escaped_backslash() {
+        let row = r#""a\\",b"#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+
+        let vec_t: Vec<_> = vec!["a\\", "b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn backslash_style_does_not_collapse_doubled_quotes() {
+        let row = r#""a""b""#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+
+        let vec_t: Vec<_> = vec!["a\"\"b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn literal_backslash_style_keeps_backslashes() {
+        let row = r#""a\"b",c"#;
+
+        let csv = CsvRow::new(row, ',', true).escape_style(EscapeStyle::Backslash);
+
+        let vec_t: Vec<_> = vec![r#""a\"b""#, "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_str_delimiter() {
+        let row = "a||b||c";
+
+        let csv = CsvRow::with_str_delimiter(row, "||", false);
+
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_str_delimiter_and_single_delim_char() {
+        let row = "a|b||c";
+
+        let csv = CsvRow::with_str_delimiter(row, "||", false);
+
+        let vec_t: Vec<_> = vec!["a|b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_str_delimiter_and_quoted_field() {
+        let row = r#"a::"b::c"::d::"#;
+
+        let csv = CsvRow::with_str_delimiter(row, "::", false);
+
+        let vec_t: Vec<_> = vec!["a", "b::c", "d", ""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_non_ascii_str_delimiter() {
+        let row = "è→→b→→→c";
+
+        let csv = CsvRow::with_str_delimiter(row, "→→", false);
+
+        let vec_t: Vec<_> = vec!["è", "b", "→c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_str_delimiter_panics() {
+        CsvRow::with_str_delimiter("a,b", "", false);
+    }
+
+    #[test]
+    fn can_parse_simple_csv_with_spaces_trimmed() {
+        let row = r#"january, "february", march, april"#;
+
+        let csv = CsvRow::new(row, ',', false).trim(true);
+
+        let vec_t: Vec<_> = vec!["january", "february", "march", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trim_keeps_spaces_within_quotes() {
+        let row = "\t\" feb, ruary \"  , march ";
+
+        let csv = CsvRow::new(row, ',', false).trim(true);
+
+        let vec_t: Vec<_> = vec![" feb, ruary ", "march"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trim_yields_empty_string_for_blank_field() {
+        let row = "january,   ,april";
+
+        let csv = CsvRow::new(row, ',', false).trim(true);
+
+        let vec_t: Vec<_> = vec!["january", "", "april"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn literal_mode_ignores_trim() {
+        let row = r#"january, "feb, ruary" , march"#;
+
+        let csv = CsvRow::new(row, ',', true).trim(true);
+
+        let vec_t: Vec<_> = vec!["january", " \"feb, ruary\" ", " march"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_yields_ok_for_well_formed_row() {
+        let row = r#"january,"feb, ""ruary""",march"#;
+
+        let csv = CsvRow::new(row, ',', false).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Ok("feb, \"ruary\"".into()),
+            Ok("march".into()),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_reports_unterminated_quote() {
+        let row = r#"january,"feb"#;
+
+        let csv = CsvRow::new(row, ',', false).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::UnterminatedQuote { byte_pos: 8 }),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn unterminated_quote_at_end_of_line_is_kept_as_is() {
+        let row = r#"january,"feb"#;
+
+        let vec_t: Vec<_> = vec!["january", "\"feb"];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let mut vec_r: Vec<_> = CsvRow::new(row, ',', false).rev().collect();
+        vec_r.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_r: Vec<_> = CsvRow::new(row, ',', true).collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+    }
+
+    #[test]
+    fn lone_quote_at_end_of_line_is_kept_as_is() {
+        let row = r#"january,""#;
+
+        let vec_t: Vec<_> = vec!["january", "\""];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let csv = CsvRow::new(row, ',', false).strict(true).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::UnterminatedQuote { byte_pos: 8 }),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn strict_try_fields_reports_unterminated_quote_at_end_of_line() {
+        let row = r#"january,"feb"#;
+
+        let csv = CsvRow::new(row, ',', false).strict(true).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::UnterminatedQuote { byte_pos: 8 }),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_reports_unexpected_quote() {
+        let row = r#"january,"feb"ruary,march"#;
+
+        let csv = CsvRow::new(row, ',', false).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::UnexpectedQuote { byte_pos: 12 }),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_reports_escaped_closing_quote_as_unterminated() {
+        let row = r#"a,"b"",c"#;
+
+        let csv = CsvRow::new(row, ',', false).try_fields();
+
+        // The escaped quote leaves the field open, so it runs to the end of the line
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("a".into()),
+            Err(CsvError::UnterminatedQuote { byte_pos: 2 }),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_reports_positions_after_trimmed_whitespace() {
+        let row = r#"a,  "b"c"#;
+
+        let csv = CsvRow::new(row, ',', false).trim(true).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("a".into()),
+            Err(CsvError::UnexpectedQuote { byte_pos: 6 }),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_reports_unescaped_quote_in_backslash_style() {
+        let row = r#""a\"b"c","d\\""#;
+
+        let csv = CsvRow::new(row, ',', false)
+            .escape_style(EscapeStyle::Backslash)
+            .try_fields();
+
+        // The stray quote reopens the field, so it runs to the end of the line
+        let vec_t: Vec<Result<_, CsvError>> = vec![Err(CsvError::UnexpectedQuote { byte_pos: 5 })];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_allows_bare_quote_in_unquoted_field() {
+        let row = r#"january,feb"ruary"#;
+
+        let csv = CsvRow::new(row, ',', false).try_fields();
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![Ok("january".into()), Ok("feb\"ruary".into())];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn escapes_string_with_newline() {
+        let expression = "a\nb";
+        let result = escape(expression, ',');
+
+        assert_eq!("\"a\nb\"", result)
+    }
+
+    #[test]
+    fn escapes_string_with_crlf() {
+        let expression = "a\r\nb";
+        let result = escape(expression, ',');
+
+        assert_eq!("\"a\r\nb\"", result)
+    }
+
+    #[test]
+    fn escapes_string_with_newline_and_quote() {
+        let expression = "a\n\"b\"";
+        let result = escape(expression, ',');
+
+        assert_eq!("\"a\n\"\"b\"\"\"", result)
+    }
+
+    #[test]
+    fn can_parse_wide_row_in_linear_time() {
+        let fields: Vec<_> = (0..10_000).map(|i| format!("\"field, {i}\"")).collect();
+        let row = fields.join(",");
+
+        let csv = CsvRow::new(&row, ',', false);
+
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(10_000, vec_r.len());
+        assert_eq!("field, 0", vec_r[0]);
+        assert_eq!("field, 9999", vec_r[9_999]);
+    }
+
+    #[test]
+    fn size_hint_is_bounded_by_remaining_delimiters() {
+        let row = r#"a,"b,c",d"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+
+        assert_eq!((1, Some(4)), csv.size_hint());
+        csv.next();
+        assert_eq!((1, Some(3)), csv.size_hint());
+        csv.next();
+        assert_eq!((1, Some(1)), csv.size_hint());
+        csv.next();
+        assert_eq!((0, Some(0)), csv.size_hint());
+    }
+
+    #[test]
+    fn size_hint_counts_trailing_empty_field() {
+        let mut csv = CsvRow::with_str_delimiter("a||", "||", false);
+
+        csv.next();
+
+        assert_eq!((1, Some(1)), csv.size_hint());
+        assert_eq!(Some("".into()), csv.next());
+        assert_eq!((0, Some(0)), csv.size_hint());
+    }
+
+    #[test]
+    fn size_hint_of_empty_row_is_zero() {
+        let csv = CsvRow::new("", ',', false);
+
+        assert_eq!((0, Some(0)), csv.size_hint())
+    }
+
+    #[test]
+    fn can_parse_tiny_csv_reversed() {
+        let row = "a,b,c,d";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["d", "c", "b", "a"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_take_last_fields_of_row() {
+        let row = "a,b,c,d";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["d", "c"];
+        let vec_r: Vec<_> = csv.rev().take(2).collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_quoted_fields_reversed() {
+        let row = r#"january,"leap day, the","The ""Coder"" Man","""quoted""",, "#;
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec![
+            " ",
+            "",
+            "\"quoted\"",
+            "The \"Coder\" Man",
+            "leap day, the",
+            "january",
+        ];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_trimmed_quoted_fields_reversed() {
+        let row = r#" "a, b" ,c, "d,e" "#;
+
+        let csv = CsvRow::new(row, ',', false).trim(true);
+
+        let vec_t: Vec<_> = vec!["d,e", "c", "a, b"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_backslash_escaped_fields_reversed() {
+        let row = r#""a\",b","c\\",d"#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+
+        let vec_t: Vec<_> = vec!["d", "c\\", "a\",b"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_str_delimited_fields_reversed() {
+        let row = r#"a||"b||c"||d||"#;
+
+        let csv = CsvRow::with_str_delimiter(row, "||", false);
+
+        let vec_t: Vec<_> = vec!["", "d", "b||c", "a"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn overlapping_str_delimiters_match_forward_when_reversed() {
+        for (row, fields) in [("a:::b", ["a", ":b"]), ("x|||y||z", ["x", "|y"])] {
+            let delimiter = &row[1..3];
+            let csv = CsvRow::with_str_delimiter(row, delimiter, false).strict(true);
+            let vec_f: Vec<_> = csv.clone().collect();
+            let mut vec_r: Vec<_> = csv.clone().rev().collect();
+            vec_r.reverse();
+
+            assert!(csv.try_fields().all(|field| field.is_ok()));
+            assert_eq!(fields[..], vec_f[..2]);
+            assert_eq!(vec_f, vec_r, "{row}");
+        }
+    }
+
+    #[test]
+    fn closing_quote_is_not_read_as_opening_quote_when_reversed() {
+        let row = r#""x,",5 in""#;
+
+        let vec_t: Vec<_> = vec!["x,", "5 in\""];
+        let mut vec_r: Vec<_> = CsvRow::new(row, ',', false).rev().collect();
+        vec_r.reverse();
+
+        assert!(CsvRow::new(row, ',', false)
+            .try_fields()
+            .all(|field| field.is_ok()));
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_from_both_ends() {
+        let row = r#"a,"b,c",d,e"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+
+        assert_eq!(Some("e".into()), csv.next_back());
+        assert_eq!(Some("a".into()), csv.next());
+        assert_eq!(Some("d".into()), csv.next_back());
+        assert_eq!(Some("b,c".into()), csv.next());
+        assert_eq!(None, csv.next_back());
+        assert_eq!(None, csv.next());
+    }
+
+    #[test]
+    fn can_parse_empty_fields_from_both_ends() {
+        let row = ",";
+
+        let mut csv = CsvRow::new(row, ',', false);
+
+        assert_eq!(Some("".into()), csv.next_back());
+        assert_eq!(Some("".into()), csv.next());
+        assert_eq!(None, csv.next_back());
+    }
+
+    #[test]
+    fn reversed_matches_forward_for_well_formed_rows() {
+        let rows = [
+            r#"january,february,"leap day, the",march,april"#,
+            r#""""","a""","""b",c"#,
+            r#"è,"é,""ü""",ö"#,
+            ",a,,",
+        ];
+
+        for row in rows {
+            let forward: Vec<_> = CsvRow::new(row, ',', false).collect();
+            let mut reversed: Vec<_> = CsvRow::new(row, ',', false).rev().collect();
+            reversed.reverse();
+
+            assert_eq!(forward, reversed, "{row}");
+        }
+    }
+
+    #[test]
+    fn orphaned_quote_is_lenient_but_not_strict() {
+        let row = r#"january,feb"ruary,march"#;
+
+        let vec_t: Vec<_> = vec!["january", "feb\"ruary", "march"];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Ok("feb\"ruary".into()),
+            Ok("march".into()),
+        ];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).try_fields().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::MalformedField { byte_pos: 11 }),
+            Ok("march".into()),
+        ];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .strict(true)
+            .try_fields()
+            .collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn premature_close_quote_is_lenient_but_not_strict() {
+        let row = r#"january,"feb"ruary,march"#;
+
+        let vec_t: Vec<_> = vec!["january", "\"feb\"ruary,march"];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::UnexpectedQuote { byte_pos: 12 }),
+        ];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).try_fields().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        // Strict mode points at the text after the quote that closed the field
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Err(CsvError::TrailingDataAfterQuote { byte_pos: 13 }),
+        ];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .strict(true)
+            .try_fields()
+            .collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn strict_accepts_well_formed_row() {
+        let row = r#"january,"feb""ruary",march"#;
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("january".into()),
+            Ok("feb\"ruary".into()),
+            Ok("march".into()),
+        ];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .strict(true)
+            .try_fields()
+            .collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn strict_accepts_backslash_escaped_quote_in_unquoted_field() {
+        let row = r#"5'10\",x"#;
+
+        let csv = CsvRow::new(row, ',', false)
+            .escape_style(EscapeStyle::Backslash)
+            .strict(true);
+
+        let vec_t: Vec<Result<_, CsvError>> = vec![Ok("5'10\"".into()), Ok("x".into())];
+        let vec_r: Vec<_> = csv.try_fields().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn unescapes_quoted_string() {
+        let result = unescape("\"a\"\"b\"", ',');
+
+        assert_eq!("a\"b", result)
+    }
+
+    #[test]
+    fn does_not_unescape_simple_string() {
+        let field = "chupacabra";
+        let result = unescape(field, ',');
+
+        assert!(matches!(result, Cow::Borrowed("chupacabra")))
+    }
+
+    #[test]
+    fn unescape_is_inverse_of_escape() {
+        for expression in [
+            "chupacabra",
+            "this is a \"test\", of course...",
+            "a\nb",
+            "\"",
+            "",
+        ] {
+            let escaped = escape(expression, ',');
+
+            assert_eq!(expression, unescape(&escaped, ','))
+        }
+    }
+
+    #[test]
+    fn escape_into_appends_to_same_buffer() {
+        let mut out = String::new();
+
+        for expression in ["chupacabra", "a,b", "", "\"quoted\"", "a\nb"] {
+            escape_into(expression, ',', &mut out);
+        }
+
+        assert_eq!("chupacabra\"a,b\"\"\"\"quoted\"\"\"\"a\nb\"", out)
+    }
+
+    #[test]
+    fn escape_into_matches_escape() {
+        for expression in [
+            "chupacabra",
+            "this is a \"test\", of course...",
+            "\"",
+            "a\r\nb",
+            "",
+        ] {
+            let mut out = String::from("prefix");
+            escape_into(expression, ',', &mut out);
+
+            assert_eq!(format!("prefix{}", escape(expression, ',')), out)
+        }
+    }
+
+    #[test]
+    fn strips_leading_bom() {
+        let row = "\u{FEFF}a,b,c";
+
+        let csv = CsvRow::new(row, ',', false).strip_bom(true);
+
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn strips_bom_before_quoted_field() {
+        let row = "\u{FEFF}\"a,b\",c";
+
+        let csv = CsvRow::new(row, ',', false).strip_bom(true);
+
+        let vec_t: Vec<_> = vec!["a,b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn keeps_bom_unless_stripped() {
+        let row = "\u{FEFF}a,b";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["\u{FEFF}a", "b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn only_strips_bom_at_start_of_line() {
+        let row = "a,\u{FEFF}b";
+
+        let csv = CsvRow::new(row, ',', false).strip_bom(true);
+
+        let vec_t: Vec<_> = vec!["a", "\u{FEFF}b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn line_of_only_bom_has_no_fields() {
+        let csv = CsvRow::new("\u{FEFF}", ',', false).strip_bom(true);
+
+        assert_eq!(0, csv.count())
+    }
+
+    #[test]
+    fn trims_trailing_carriage_return() {
+        let row = "a,b,c\r";
+
+        let csv = CsvRow::new(row, ',', false).trim_cr(true);
+
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trims_one_carriage_return_after_delimiter() {
+        let row = "a,\r\r";
+
+        let csv = CsvRow::new(row, ',', false).trim_cr(true);
+
+        let vec_t: Vec<_> = vec!["a", "\r"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn keeps_carriage_return_unless_trimmed() {
+        let row = "a,b,c\r";
+
+        let csv = CsvRow::new(row, ',', false);
+
+        let vec_t: Vec<_> = vec!["a", "b", "c\r"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn literal_mode_ignores_trim_cr() {
+        let row = "a,\"b\",c\r";
+
+        let csv = CsvRow::new(row, ',', true).trim_cr(true);
+
+        let vec_t: Vec<_> = vec!["a", "\"b\"", "c\r"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trim_cr_and_mode_can_be_set_in_either_order() {
+        let row = "a,\"b\"\r";
+
+        let vec_l: Vec<_> = vec!["a", "\"b\"\r"];
+        let vec_u: Vec<_> = vec!["a", "b"];
+
+        let csv = CsvRow::new(row, ',', false)
+            .trim_cr(true)
+            .mode(FieldMode::Literal);
+        assert_eq!(vec_l[..], csv.collect::<Vec<_>>()[..]);
+
+        let csv = CsvRow::new(row, ',', false)
+            .mode(FieldMode::Literal)
+            .trim_cr(true);
+        assert_eq!(vec_l[..], csv.collect::<Vec<_>>()[..]);
+
+        let csv = CsvRow::new(row, ',', true)
+            .trim_cr(true)
+            .mode(FieldMode::Unescaped);
+        assert_eq!(vec_u[..], csv.clone().collect::<Vec<_>>()[..]);
+        assert_eq!(["b", "a"][..], csv.rev().collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn line_of_only_carriage_return_has_no_fields() {
+        let csv = CsvRow::new("\r", ',', false).trim_cr(true);
+
+        assert_eq!(0, csv.count())
+    }
+
+    #[test]
+    fn keeps_returning_none_past_the_end() {
+        let row = "a,";
+
+        let mut csv = CsvRow::new(row, ',', false);
+
+        assert_eq!(Some("a".into()), csv.next());
+        assert_eq!(Some("".into()), csv.next());
+
+        for _ in 0..5 {
+            assert_eq!(None, csv.next());
+            assert_eq!(None, csv.next_back());
+        }
+    }
+
+    #[test]
+    fn clone_does_not_disturb_original() {
+        let row = r#"a,"b,c",d,e"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+        csv.next();
+
+        let vec_t: Vec<_> = vec!["b,c", "d", "e"];
+        let vec_r: Vec<_> = csv.clone().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let mut lookahead = csv.clone();
+        lookahead.next();
+        lookahead.next();
+
+        assert_eq!(Some("b,c".into()), csv.next());
+        assert_eq!(Some("e".into()), lookahead.next());
+    }
+
+    #[test]
+    fn counts_fields() {
+        assert_eq!(4, CsvRow::field_count("a,b,c,d", ','));
+        assert_eq!(1, CsvRow::field_count("january", ','));
+        assert_eq!(0, CsvRow::field_count("", ','));
+        assert_eq!(3, CsvRow::field_count("january,,", ','));
+    }
+
+    #[test]
+    fn counts_fields_with_quoted_delimiter() {
+        let row = r#"january,"leap day, the","The ""Coder"" Man, again",march"#;
+
+        assert_eq!(4, CsvRow::field_count(row, ','));
+        assert_eq!(
+            CsvRow::new(row, ',', false).collect::<Vec<_>>().len(),
+            CsvRow::field_count(row, ',')
+        );
+    }
+
+    #[test]
+    fn can_get_field_by_index() {
+        let row = r#"january,"leap day, the","The ""Coder"" Man",march"#;
+
+        assert_eq!(Some("january".into()), CsvRow::field(row, ',', 0, false));
+        assert_eq!(
+            Some("leap day, the".into()),
+            CsvRow::field(row, ',', 1, false)
+        );
+        assert_eq!(
+            Some("The \"Coder\" Man".into()),
+            CsvRow::field(row, ',', 2, false)
+        );
+        assert_eq!(
+            Some("\"The \"\"Coder\"\" Man\"".into()),
+            CsvRow::field(row, ',', 2, true)
+        );
+        assert_eq!(Some("march".into()), CsvRow::field(row, ',', 3, false));
+    }
+
+    #[test]
+    fn field_out_of_range_is_none() {
+        let row = r#"january,"leap day, the""#;
+
+        assert_eq!(None, CsvRow::field(row, ',', 2, false));
+        assert_eq!(None, CsvRow::field(row, ',', usize::MAX, false));
+        assert_eq!(None, CsvRow::field("", ',', 0, false));
+    }
+
+    #[test]
+    fn remainder_shrinks_as_fields_are_consumed() {
+        let row = r#"january,"leap, day",march,"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+
+        assert_eq!(row, csv.remainder());
+        csv.next();
+        assert_eq!(r#""leap, day",march,"#, csv.remainder());
+        csv.next();
+        assert_eq!("march,", csv.remainder());
+        csv.next();
+        assert_eq!("", csv.remainder());
+        csv.next();
+        assert_eq!("", csv.remainder());
+        assert_eq!(None, csv.next());
+        assert_eq!("", csv.remainder());
+    }
+
+    #[test]
+    fn remainder_excludes_fields_parsed_from_the_back() {
+        let mut csv = CsvRow::new("a,b,c,d", ',', false);
+
+        csv.next();
+        csv.next_back();
+
+        assert_eq!("b,c", csv.remainder())
+    }
+
+    #[test]
+    fn reset_reparses_identically() {
+        let row = r#"static,"fnord, ""squared""",,'quoted'"#;
+
+        let mut csv = CsvRow::new(row, ',', false).trim(true);
+        let vec_t: Vec<_> = csv.by_ref().collect();
+        csv.reset();
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn reset_rewinds_fields_parsed_from_the_back() {
+        let mut csv = CsvRow::new("a,b,c", ',', false);
+        csv.next();
+        csv.next_back();
+        csv.reset();
+
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn debug_shows_cursor_positions() {
+        let mut csv = CsvRow::new("ñandú,b,c", ',', false);
+        csv.next();
+
+        let debug = format!("{csv:?}");
+
+        assert!(debug.contains(r#"line: "ñandú,b,c""#));
+        assert!(debug.contains("byte_pos: 8"));
+        assert!(debug.contains("char_pos: 6"));
+    }
+
+    #[test]
+    fn debug_truncates_long_lines() {
+        let row = "a,".repeat(1000);
+
+        let debug = format!("{:?}", CsvRow::new(&row, ',', false));
+
+        assert!(debug.contains(&format!("line: {:?}...", &row[..32])));
+        assert!(debug.len() < 200);
+    }
+
+    #[test]
+    fn escape_quoted_quotes_simple_string() {
+        assert_eq!("\"chupacabra\"", escape_quoted("chupacabra"));
+        assert_eq!("\"\"", escape_quoted(""));
+    }
+
+    #[test]
+    fn escape_quoted_escapes_complex_string() {
+        let expression = "this is a \"test\", of course...";
+
+        assert_eq!(escape(expression, ','), escape_quoted(expression));
+    }
+
+    #[test]
+    fn detects_comma_delimiter() {
+        let line = r#"name,"city; state",zip"#;
+
+        assert_eq!(Some(','), detect_delimiter(line, &[',', ';', '\t', '|']));
+    }
+
+    #[test]
+    fn detects_semicolon_delimiter() {
+        let line = r#"name;"city, state";zip;country"#;
+
+        assert_eq!(Some(';'), detect_delimiter(line, &[',', ';', '\t', '|']));
+    }
+
+    #[test]
+    fn detect_delimiter_returns_none_for_single_field() {
+        assert_eq!(None, detect_delimiter("chupacabra", &[',', ';', '\t', '|']));
+        assert_eq!(None, detect_delimiter("a,b", &[]));
+    }
+
+    #[test]
+    fn spanned_ranges_index_back_into_line() {
+        let row = r#"static, "fnord, ""squared""",,ñandú"#;
+
+        let csv = CsvRow::new(row, ',', false).trim(true);
+        let vec_t: Vec<_> = vec!["static", r#" "fnord, ""squared""""#, "", "ñandú"];
+        let vec_r: Vec<_> = csv.spanned().map(|(range, _)| &row[range]).collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn spanned_from_the_back_matches_forward() {
+        let row = r#"a,"b,c",,d"#;
+
+        let mut vec_t: Vec<_> = CsvRow::new(row, ',', false).spanned().collect();
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).spanned().rev().collect();
+        vec_t.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_parse_csv_with_delimiter_set() {
+        let row = "a,b\tc";
+
+        let csv = CsvRow::with_delimiters(row, &[',', '\t'], false);
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn delimiter_set_respects_quotes() {
+        let row = "\"a,b\"\t\"c\td\",ñ¦é";
+
+        let csv = CsvRow::with_delimiters(row, &[',', '\t', '¦'], false);
+        let vec_t: Vec<_> = vec!["a,b", "c\td", "ñ", "é"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn delimiter_set_parses_from_the_back() {
+        let row = "\"a,b\"\t\"c\td\",ñ¦é";
+
+        let csv = CsvRow::with_delimiters(row, &[',', '\t', '¦'], false);
+        let vec_t: Vec<_> = vec!["é", "ñ", "c\td", "a,b"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn nullable_yields_null_for_bare_empty_field() {
+        let row = "a,,b";
+
+        let csv = CsvRow::new(row, ',', false).nullable();
+        let vec_t: Vec<_> = vec![
+            Field::Value("a".into()),
+            Field::Null,
+            Field::Value("b".into()),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn nullable_yields_empty_for_quoted_empty_field() {
+        let row = r#"a,"",b"#;
+
+        let csv = CsvRow::new(row, ',', false).nullable();
+        let vec_t: Vec<_> = vec![
+            Field::Value("a".into()),
+            Field::Empty,
+            Field::Value("b".into()),
+        ];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn nullable_literal_keeps_quoted_empty_field() {
+        let row = r#"a,"""#;
+
+        let csv = CsvRow::new(row, ',', true).nullable();
+        let vec_t: Vec<_> = vec![Field::Value("a".into()), Field::Value(r#""""#.into())];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn parse_fields_parses_numbers() {
+        let row = r#"1,"2",3"#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<Result<u32, _>> = vec![Ok(1), Ok(2), Ok(3)];
+        let vec_r: Vec<_> = csv.parse_fields::<u32>().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn parse_fields_reports_unparsable_field() {
+        let row = "1,x,3,";
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_r: Vec<_> = csv.parse_fields::<u32>().collect();
+
+        assert_eq!(Ok(1), vec_r[0]);
+        assert!(vec_r[1].is_err());
+        assert_eq!(Ok(3), vec_r[2]);
+        assert_eq!("".parse::<u32>(), vec_r[3]);
+    }
+
+    #[test]
+    fn can_parse_tsv_with_quoted_tab() {
+        let row = "a\t\"b\tc\"\td";
+
+        let csv = CsvRow::tsv(row, false);
+        let vec_t: Vec<_> = vec!["a", "b\tc", "d"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn with_counted_count_matches_collected_fields() {
+        for row in ["", "a", "a,b,", r#"january,"leap, day",,"mar""ch""#] {
+            let (count, csv) = CsvRow::with_counted(row, ',', false);
+            let vec_r: Vec<_> = csv.collect();
+
+            assert_eq!(vec_r.len(), count);
+        }
+    }
+
+    #[test]
+    fn skip_space_before_quote_recognizes_padded_quote() {
+        let row = r#"january, "february",  "leap, day""#;
+
+        let csv = CsvRow::new(row, ',', false).skip_space_before_quote(true);
+        let vec_t: Vec<_> = vec!["january", "february", "leap, day"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn skip_space_before_quote_keeps_unquoted_whitespace() {
+        let row = r#" january , "february""#;
+
+        let csv = CsvRow::new(row, ',', false).skip_space_before_quote(true);
+        let vec_t: Vec<_> = vec![" january ", "february"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn padded_quote_is_literal_by_default() {
+        let row = r#"january, "february""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["january", r#" "february""#];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn skip_space_before_quote_parses_from_the_back() {
+        let row = r#"a, "b, c", d"#;
+
+        let csv = CsvRow::new(row, ',', false).skip_space_before_quote(true);
+        let vec_t: Vec<_> = vec![" d", "b, c", "a"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn skip_space_before_quote_reports_errors_at_quote() {
+        let row = r#"a,  "b"#;
+
+        let mut csv = CsvRow::new(row, ',', false)
+            .skip_space_before_quote(true)
+            .try_fields();
+        csv.next();
+
+        assert_eq!(
+            Some(Err(CsvError::UnterminatedQuote { byte_pos: 4 })),
+            csv.next()
+        );
+    }
+
+    #[test]
+    fn eq_fields_matches_equal_fields() {
+        let row = r#"january,"The ""Coder"" Man",,"#;
+
+        assert!(CsvRow::new(row, ',', false).eq_fields(&["january", "The \"Coder\" Man", "", ""]));
+        assert!(CsvRow::new("", ',', false).eq_fields::<&str>(&[]));
+    }
+
+    #[test]
+    fn eq_fields_rejects_different_fields() {
+        let row = "a,b,c";
+
+        assert!(!CsvRow::new(row, ',', false).eq_fields(&["a", "x", "c"]));
+        assert!(!CsvRow::new(row, ',', false).eq_fields(&["a", "b"]));
+        assert!(!CsvRow::new(row, ',', false).eq_fields(&["a", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn escape_with_style_produces_each_style() {
+        let expression = r#"The "Coder" Man, \o/"#;
+
+        assert_eq!(
+            r#""The ""Coder"" Man, \o/""#,
+            escape_with_style(expression, ',', EscapeStyle::Doubled)
+        );
+        assert_eq!(
+            r#""The \"Coder\" Man, \\o/""#,
+            escape_with_style(expression, ',', EscapeStyle::Backslash)
+        );
+    }
+
+    #[test]
+    fn escape_with_style_round_trips_each_style() {
+        let expressions = [
+            "chupacabra",
+            r#"The "Coder" Man, \o/"#,
+            r"C:\temp",
+            "",
+            r#"\""#,
+        ];
+
+        for escape_style in [
+            EscapeStyle::Doubled,
+            EscapeStyle::Backslash,
+            EscapeStyle::Char('^'),
+        ] {
+            for expression in expressions {
+                let escaped = escape_with_style(expression, ',', escape_style);
+                let line = format!("{escaped},end");
+
+                let csv = CsvRow::new(&line, ',', false).escape_style(escape_style);
+                let vec_t: Vec<_> = vec![expression, "end"];
+                let vec_r: Vec<_> = csv.collect();
+
+                assert_eq!(
+                    vec_t[..],
+                    vec_r[..],
+                    "{expression} was escaped as {escaped}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn escape_with_backslash_style_borrows_simple_string() {
+        let expression = "chupacabra";
+
+        assert!(matches!(
+            escape_with_style(expression, ',', EscapeStyle::Backslash),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn skip_fields_then_next_returns_following_field() {
+        let row = r#""jan, uary","The ""Coder"" Man","march",april,may"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+        csv.skip_fields(3);
+
+        assert_eq!(Some("april".into()), csv.next());
+    }
+
+    #[test]
+    fn skip_fields_past_the_end_finishes_row() {
+        let mut csv = CsvRow::new("a,b", ',', false);
+        csv.skip_fields(5);
+
+        assert_eq!(None, csv.next());
+    }
+
+    #[test]
+    fn collect_array_with_exact_field_count() {
+        let row = r#"january,"leap, day",march"#;
+
+        let result = CsvRow::new(row, ',', false).collect_array();
+
+        assert_eq!(
+            Ok(["january".into(), "leap, day".into(), "march".into()]),
+            result
+        );
+    }
+
+    #[test]
+    fn collect_array_with_too_few_fields() {
+        let row = r#"january,"leap, day""#;
+
+        let result = CsvRow::new(row, ',', false).collect_array::<3>();
+
+        assert_eq!(
+            Err(CsvError::TooFewFields {
+                expected: 3,
+                found: 2
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn try_collect_strict_collects_clean_row() {
+        let row = r#"january,"The ""Coder"" Man",,march"#;
+
+        let vec_t: Vec<_> = CsvRow::new(row, ',', false).collect();
+        let result = CsvRow::new(row, ',', false).try_collect_strict();
+
+        assert_eq!(Ok(vec_t), result)
+    }
+
+    #[test]
+    fn try_collect_strict_stops_at_stray_quote() {
+        let row = r#"january,feb"ruary,"march"#;
+
+        let result = CsvRow::new(row, ',', false).try_collect_strict();
+
+        assert_eq!(Err(CsvError::MalformedField { byte_pos: 11 }), result)
+    }
+
+    #[test]
+    fn collect_array_with_too_many_fields() {
+        let row = "a,b,c,";
+
+        let result = CsvRow::new(row, ',', false).collect_array::<3>();
+
+        assert_eq!(
+            Err(CsvError::TooManyFields {
+                expected: 3,
+                found: 4
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn for_each_unescaped_visits_collected_values() {
+        let rows = [
+            r#"january,"The ""Coder"" Man",,"""quoted""","#,
+            r#" "a ""b""" , c "#,
+            r#"'a''b',"c""#,
+        ];
+
+        for row in rows {
+            for csv in [
+                CsvRow::new(row, ',', false).trim(true),
+                CsvRow::new(row, ',', true),
+                CsvRow::with_quote(row, ',', false, '\''),
+            ] {
+                let vec_t: Vec<_> = csv.clone().collect();
+                let mut vec_r: Vec<String> = Vec::new();
+                csv.for_each_unescaped(|field| vec_r.push(field.to_owned()));
+
+                assert_eq!(vec_t[..], vec_r[..])
+            }
+        }
+    }
+
+    #[test]
+    fn for_each_unescaped_handles_backslash_style() {
+        let row = r#""a\"b",c\\d"#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Backslash);
+        let vec_t: Vec<_> = csv.clone().collect();
+        let mut vec_r: Vec<String> = Vec::new();
+        csv.for_each_unescaped(|field| vec_r.push(field.to_owned()));
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trailing_quoted_delimiter_field() {
+        let row = r#""a",",""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["a", ","];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn quoted_field_with_escaped_quote_before_delimiter() {
+        let row = r#""a"",b",""",c""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["a\",b", "\",c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn trailing_quoted_delimiter_field_from_the_back() {
+        let row = r#""a",",""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec![",", "a"];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn cursor_field_increments_with_next() {
+        let row = r#"january,"leap, day",,march"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+        let mut positions = vec![csv.cursor()];
+
+        while csv.next().is_some() {
+            positions.push(csv.cursor());
+        }
+
+        let fields: Vec<_> = positions.iter().map(|p| p.field).collect();
+        let bytes: Vec<_> = positions.iter().map(|p| p.byte).collect();
+
+        assert_eq!([1, 2, 3, 4, 5], fields[..]);
+        assert_eq!([0, 8, 20, 21, 26], bytes[..]);
+    }
+
+    #[test]
+    fn cursor_counts_skipped_fields_and_resets() {
+        let mut csv = CsvRow::new("a,b,c", ',', false);
+
+        csv.skip_fields(2);
+        assert_eq!(Position { field: 3, byte: 4 }, csv.cursor());
+
+        csv.reset();
+        assert_eq!(Position { field: 1, byte: 0 }, csv.cursor());
+    }
+
+    #[test]
+    fn iterator_position_is_not_shadowed() {
+        let mut csv = CsvRow::new("a,b,c", ',', false);
+
+        assert_eq!(Some(1), csv.position(|field| field == "b"));
+        assert_eq!(Position { field: 3, byte: 4 }, csv.cursor());
+    }
+
+    #[test]
+    fn adjacent_quoted_fields_ending_in_escaped_quote() {
+        let row = r#""a""","b""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["a\"", "b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn adjacent_quoted_fields_with_delimiters() {
+        let row = r#""""",",",","",""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["\"", ",", ",\","];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn field_after_closing_quote_is_not_closed_early() {
+        let row = r#""a",b",c"#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["a", "b\"", "c"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn collapses_consecutive_delimiters() {
+        let row = "a,,,b";
+
+        let csv = CsvRow::new(row, ',', false).collapse_delimiters(true);
+        let vec_t: Vec<_> = vec!["a", "b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn keeps_empty_fields_unless_collapsing() {
+        let row = "a,,,b";
+
+        let csv = CsvRow::new(row, ',', false).collapse_delimiters(false);
+        let vec_t: Vec<_> = vec!["a", "", "", "b"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn does_not_collapse_delimiters_within_quotes() {
+        let row = r#"a,,"b,,c",,d,"#;
+
+        let csv = CsvRow::new(row, ',', false).collapse_delimiters(true);
+        let vec_t: Vec<_> = vec!["a", "b,,c", "d", ""];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_collapse_delimiters_reversed() {
+        for row in [",,a,,b", "a,,,b", r#"a,,"b,,c",,d,,"#] {
+            let csv = CsvRow::new(row, ',', false).collapse_delimiters(true);
+            let mut vec_t: Vec<_> = csv.clone().collect();
+            vec_t.reverse();
+            let vec_r: Vec<_> = csv.rev().collect();
+
+            assert_eq!(vec_t[..], vec_r[..], "{row}");
+        }
+    }
+
+    #[test]
+    fn try_fields_rejects_field_over_max_len() {
+        let row = format!("a,\"{}", "b".repeat(100));
+
+        let csv = CsvRow::new(&row, ',', false)
+            .max_field_len(Some(16))
+            .try_fields();
+        let vec_t: Vec<_> = vec![Ok("a".into()), Err(CsvError::FieldTooLong { byte_pos: 2 })];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn try_fields_accepts_field_at_max_len() {
+        let row = r#"abcd,"ab""#;
+
+        let csv = CsvRow::new(row, ',', false)
+            .max_field_len(Some(4))
+            .try_fields();
+        let vec_t: Vec<Result<_, CsvError>> = vec![Ok("abcd".into()), Ok("ab".into())];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn unescapes_quoted_json_field() {
+        let row = r#""{""a"":""b""}""#;
+
+        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec![r#"{"a":"b"}"#];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn quoted_json_fields_with_delimiters_are_well_formed() {
+        let row = r#"x,"{""a"":{""b"":[1,""c""]}}","{""d"":"",""}",y"#;
+
+        let vec_t: Vec<_> = vec!["x", r#"{"a":{"b":[1,"c"]}}"#, r#"{"d":","}"#, "y"];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .strict(true)
+            .try_fields()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let mut vec_b: Vec<_> = CsvRow::new(row, ',', false).rev().collect();
+        vec_b.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..])
+    }
+
+    #[test]
+    fn pairs_yields_overlapping_pairs() {
+        let row = "a,b,c";
+
+        let csv = CsvRow::new(row, ',', false).pairs();
+        let vec_t: Vec<(Cow<str>, Cow<str>)> =
+            vec![("a".into(), "b".into()), ("b".into(), "c".into())];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn pairs_yields_nothing_for_single_field() {
+        let mut csv = CsvRow::new(r#""a,b""#, ',', false).pairs();
+
+        assert_eq!(None, csv.next())
+    }
+
+    #[test]
+    fn has_more_counts_field_after_trailing_delimiter() {
+        let mut csv = CsvRow::new("a,", ',', false);
+
+        assert!(csv.has_more());
+        assert_eq!(Some("a".into()), csv.next());
+        assert!(csv.has_more());
+        assert_eq!(Some("".into()), csv.next());
+        assert!(!csv.has_more());
+    }
+
+    #[test]
+    fn has_more_is_false_once_consumed() {
+        let mut csv = CsvRow::new("a,b", ',', false);
+        csv.by_ref().for_each(drop);
+
+        assert!(!csv.has_more());
+        assert!(!CsvRow::new("", ',', false).has_more());
+    }
+
+    #[test]
+    fn parse_decimals_parses_comma_separator() {
+        let row = "1,5;2,75";
+
+        let csv = CsvRow::new(row, ';', false).parse_decimals(',');
+        let vec_t: Vec<f64> = vec![1.5, 2.75];
+        let vec_r: Vec<_> = csv.collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn parse_decimals_reports_unparsable_field() {
+        let row = r#""-0,5";1,2,3"#;
+
+        let vec_r: Vec<_> = CsvRow::new(row, ';', false).parse_decimals(',').collect();
+
+        assert_eq!(Ok(-0.5), vec_r[0]);
+        assert!(vec_r[1].is_err());
+    }
+
+    #[test]
+    fn empty_fields_point_into_line() {
+        let row = String::from("a,,b,");
+        let range = row.as_bytes().as_ptr_range();
+
+        for field in CsvRow::new(&row, ',', false) {
+            let Cow::Borrowed(field) = field else {
+                panic!("{field:?} was not borrowed");
+            };
 
-            byte_length += c.len_utf8();
-            self.prev_char = Some(c);
+            assert!(range.start <= field.as_ptr() && field.as_ptr() <= range.end);
         }
+    }
 
-        // Get the full field from start to finish
-        let mut result = match byte_length {
-            0 => "",
-            _ => &self.line[self.byte_pos..self.byte_pos + byte_length],
-        };
-
-        // Confirm that the field ends with a " as well.
-        // (Rust does not have a shortcircuited boolean assignment operator, so no &&= here.)
-        quoted = quoted && result.ends_with('"');
+    #[test]
+    fn unquoted_matches_split() {
+        let rows = [
+            "a,b,c",
+            "a,,b,",
+            ",",
+            r#"a,"b,c",d"#,
+            r#""""",x"#,
+            "è,\"é\", ü ",
+        ];
 
-        self.char_pos += result.chars().count() + 1;
-        self.byte_pos += result.len() + self.delimiter.len_utf8();
+        for row in rows {
+            let vec_t: Vec<_> = row.split(',').collect();
+            let vec_r: Vec<_> = CsvRow::unquoted(row, ',').collect();
+            let mut vec_b: Vec<_> = CsvRow::unquoted(row, ',').rev().collect();
+            vec_b.reverse();
 
-        if self.literal {
-            return Some(Cow::Borrowed(result));
-        } else {
-            // If the field is in quotes, trim them off
-            if quoted {
-                result = &result[1..result.len() - 1];
-            }
+            assert_eq!(vec_t[..], vec_r[..], "{row}");
+            assert_eq!(vec_t[..], vec_b[..], "{row}");
+        }
+    }
 
-            let result = match result.contains("\"\"") {
-                true => Some(Cow::Owned(result.replace("\"\"", "\""))),
-                false => Some(Cow::Borrowed(result)),
-            };
+    #[test]
+    fn unquoted_splits_at_str_and_set_delimiters_and_terminators() {
+        let csv = CsvRow {
+            quoting: false,
+            ..CsvRow::with_str_delimiter(r#"a::"b::c"::"#, "::", false)
+        };
+        assert_eq!(vec!["a", "\"b", "c\"", ""], csv.collect::<Vec<_>>());
 
-            return result;
+        let csv = CsvRow {
+            quoting: false,
+            ..CsvRow::with_delimiters("a;b\tc", &[';', '\t'], false)
         };
+        assert_eq!(vec!["a", "b", "c"], csv.collect::<Vec<_>>());
+
+        let mut csv = CsvRow::unquoted("a,b\r\nc,d", ',');
+        csv.terminator = Some('\n');
+        assert_eq!(vec!["a", "b"], csv.by_ref().collect::<Vec<_>>());
+        assert_eq!("c,d", csv.remainder());
     }
-}
 
-/// Returns `Cow::Owned<str> if `expression` requires escaping to be RFC-4180 compliant.
-/// 
-/// Returns `Cow::Borrowed<str>` referencing `expression` if it does not.
-///
-/// # Arguments
-///
-/// * `expression` - A string slice that holds the value to escape
-/// * `delimiter` - A char that represents the delimiter used in the CSV document
-///
-/// # Examples
-///
-/// ```
-/// use csvrow::escape;
-/// let expression = "chupacabra";
-/// let result = escape(&expression, ',');
-/// 
-/// assert_eq!(expression, result);
-/// 
-/// let expression = "this is a \"test\", of course...";
-/// let result = escape(&expression, ',');
-/// 
-/// assert_eq!("\"this is a \"\"test\"\", of course...\"", result)
-/// ```
-pub fn escape(expression: &str, delimiter: char) -> Cow<str> {
-    
-    match expression.contains(delimiter) || expression.contains("\"") {
-        true => Cow::Owned (format!("\"{}\"", expression.replace("\"", "\"\""))),
-        false => Cow::Borrowed(expression),
+    #[test]
+    fn unquoted_fields_are_borrowed_and_well_formed() {
+        let csv = CsvRow::unquoted(r#"a"b,"c"#, ',').strict(true).try_fields();
+
+        for field in csv {
+            assert!(matches!(field, Ok(Cow::Borrowed(_))));
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
+    #[test]
+    fn raw_returns_field_as_written() {
+        let mut csv = CsvRow::new(r#""a""b", c ,"#, ',', false).trim(true);
 
-    use super::*;
+        assert_eq!(None, csv.raw());
+        assert_eq!(Some("a\"b".into()), csv.next());
+        assert_eq!(Some(r#""a""b""#), csv.raw());
+        assert_eq!(Some("".into()), csv.next_back());
+        assert_eq!(Some(""), csv.raw());
+        assert_eq!(Some("c".into()), csv.next());
+        assert_eq!(Some(" c "), csv.raw());
+    }
 
     #[test]
-    fn can_parse_tiny_csv() {
-        let row = "a,b,c,d";
+    fn lone_quote_is_a_field_of_its_own() {
+        let row = r#"a,",b"#;
+
         let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["a", "\"", "b"];
+        let vec_r: Vec<_> = csv.clone().collect();
+        let mut vec_b: Vec<_> = csv.rev().collect();
+        vec_b.reverse();
 
-        // for field in csv {
-        //     println!("{field:?}");
-        // }
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..])
+    }
 
-        let vec_t: Vec<_> = vec!["a", "b", "c", "d"];
+    #[test]
+    fn lone_quote_is_reported_as_unterminated() {
+        let row = r#"a, " ,b"#;
+
+        let csv = CsvRow::new(row, ',', false).trim(true).try_fields();
+        let vec_t: Vec<Result<_, CsvError>> = vec![
+            Ok("a".into()),
+            Err(CsvError::UnterminatedQuote { byte_pos: 3 }),
+            Ok("b".into()),
+        ];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_empty_row() {
-        let row = "";
-
-        let csv = CsvRow::new(row, ',', false);
+    fn try_fields_rejects_embedded_nul() {
+        let row = "a,\"b\0c\",d";
 
-        let vec_t: Vec<Cow<'_, str>> = vec![];
+        let csv = CsvRow::new(row, ',', false).reject_nul(true).try_fields();
+        let vec_t: Vec<_> = vec![
+            Ok("a".into()),
+            Err(CsvError::NulByte { byte_pos: 4 }),
+            Ok("d".into()),
+        ];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_simple_csv() {
-        let row = r#"january,february,march,april"#;
+    fn nul_is_kept_unless_rejected() {
+        let row = "a,b\0c";
 
-        let csv = CsvRow::new(row, ',', false);
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false).try_fields().collect();
 
-        let vec_t: Vec<_> = vec!["january", "february", "march", "april"];
+        assert_eq!(Ok("b\0c".into()), vec_r[1]);
+    }
+
+    #[test]
+    fn trim_mode_unquoted_keeps_quoted_contents() {
+        let row = r#" a , "  b  " "#;
+
+        let csv = CsvRow::new(row, ',', false).trim_mode(TrimMode::Unquoted);
+        let vec_t: Vec<_> = vec!["a", "  b  "];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_tiny_csv_with_non_ascii() {
-        let row = r#"è,b,c,d"#;
+    fn trim_mode_all_trims_quoted_contents() {
+        let row = r#" a , "  b  ",""" c """#;
 
-        let csv = CsvRow::new(row, ',', false);
-
-        let vec_t: Vec<_> = vec!["è", "b", "c", "d"];
+        let csv = CsvRow::new(row, ',', false).trim_mode(TrimMode::All);
+        let vec_t: Vec<_> = vec!["a", "b", "\" c \""];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_one_field_csv() {
-        let row = "january";
+    fn trim_chars_strips_custom_chars() {
+        let row = "*a*,*b*";
 
-        let csv = CsvRow::new(row, ',', false);
+        let csv = CsvRow::new(row, ',', false).trim_chars(&['*']);
 
-        let vec_t: Vec<_> = vec!["january"];
+        let vec_t: Vec<_> = vec!["a", "b"];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn blank_field_mid_string() {
-        let row = "january,,april";
+    fn trim_chars_unquotes_padded_fields() {
+        let row = r#"*"a,*"*, b "#;
 
-        let csv = CsvRow::new(row, ',', false);
+        let csv = CsvRow::new(row, ',', false).trim_chars(&['*']);
 
-        let vec_t: Vec<_> = vec!["january", "", "april"];
+        let vec_t: Vec<_> = vec!["a,*", " b "];
+        let vec_r: Vec<_> = csv.clone().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(
+            vec_t,
+            csv.rev()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect::<Vec<_>>()
+        )
+    }
+
+    #[test]
+    fn trim_mode_off_keeps_all_whitespace() {
+        let row = r#" a , "  b  " "#;
+
+        let csv = CsvRow::new(row, ',', false)
+            .trim(true)
+            .trim_mode(TrimMode::Off);
+        let vec_t: Vec<_> = vec![" a ", r#" "  b  " "#];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn trailing_comma_yields_empty_string() {
-        let row = "january,";
+    fn parse_autodetect_chooses_delimiter_per_line() {
+        let candidates = [',', '\t'];
 
-        let csv = CsvRow::new(row, ',', false);
+        let (comma, comma_fields) = parse_autodetect(r#"a,"b	c",d"#, &candidates);
+        let (tab, tab_fields) = parse_autodetect("a\tb,c\td\te", &candidates);
 
-        let vec_t: Vec<_> = vec!["january", ""];
-        let vec_r: Vec<_> = csv.collect();
+        assert_eq!(',', comma);
+        assert_eq!(vec!["a", "b\tc", "d"], comma_fields);
+        assert_eq!('\t', tab);
+        assert_eq!(vec!["a", "b,c", "d", "e"], tab_fields);
+    }
 
-        assert_eq!(vec_t[..], vec_r[..])
+    #[test]
+    fn parse_autodetect_falls_back_to_first_candidate() {
+        let (single, single_fields) = parse_autodetect("abc", &[';', '|']);
+        let (tied, tied_fields) = parse_autodetect("a|b;c", &[';', '|']);
+
+        assert_eq!(';', single);
+        assert_eq!(vec!["abc"], single_fields);
+        assert_eq!(';', tied);
+        assert_eq!(vec!["a|b", "c"], tied_fields);
     }
 
     #[test]
-    fn can_parse_simple_csv_with_quoted_field() {
-        let row = r#"january,february,"leap day",march,april"#;
+    fn count_matches_collected_fields() {
+        let rows = [
+            "",
+            "a",
+            "a,",
+            r#"a,"b,""c",d"#,
+            r#"a,",b"#,
+            r#""a"b,c"#,
+            " a , \"b\" ",
+        ];
 
-        let csv = CsvRow::new(row, ',', false);
+        for row in rows {
+            let mut csv = CsvRow::new(row, ',', false).trim(true);
+            csv.skip_fields(1);
 
-        let vec_t: Vec<_> = vec!["january", "february", "leap day", "march", "april"];
+            assert_eq!(csv.clone().collect::<Vec<_>>().len(), csv.count(), "{row}");
+        }
+    }
+
+    #[test]
+    fn into_owned_vec_matches_owned_collect() {
+        let row = r#"january,"The ""Coder"" Man",,"leap, day""#;
+
+        let vec_t: Vec<String> = CsvRow::new(row, ',', false)
+            .map(|c| c.into_owned())
+            .collect();
+        let vec_r = CsvRow::new(row, ',', false).into_owned_vec();
+
+        assert_eq!(vec_t, vec_r);
+        assert_eq!(4, vec_r.capacity());
+    }
+
+    #[test]
+    fn strict_reports_trailing_data_after_backslash_style_quote() {
+        let row = r#""a\"b"c"#;
+
+        let mut csv = CsvRow::new(row, ',', false)
+            .escape_style(EscapeStyle::Backslash)
+            .strict(true)
+            .try_fields();
+
+        assert_eq!(
+            Some(Err(CsvError::TrailingDataAfterQuote { byte_pos: 6 })),
+            csv.next()
+        )
+    }
+
+    #[test]
+    fn map_fields_receives_unescaped_fields() {
+        let row = r#"january,"The ""Coder"" Man",è"#;
+
+        let csv = CsvRow::new(row, ',', false).map_fields(|f| f.to_uppercase().into());
+        let vec_t: Vec<_> = vec!["JANUARY", "THE \"CODER\" MAN", "È"];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_csv_with_quoted_field_containing_delim() {
-        let row = r#"january,february,"leap day, the",march,april"#;
+    fn field_modes_on_the_same_line() {
+        let row = r#""a""b",c,"""#;
 
-        let csv = CsvRow::new(row, ',', false);
+        let vec_t: Vec<_> = vec!["a\"b", "c", ""];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .mode(FieldMode::Unescaped)
+            .collect();
 
-        let vec_t: Vec<_> = vec!["january", "february", "leap day, the", "march", "april"];
-        let vec_r: Vec<_> = csv.collect();
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_t: Vec<_> = vec![r#""a"b""#, "c", r#""""#];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .mode(FieldMode::KeepQuotes)
+            .collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_t: Vec<_> = vec![r#"a""b"#, "c", ""];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .mode(FieldMode::StripQuotes)
+            .collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let vec_t: Vec<_> = vec![r#""a""b""#, "c", r#""""#];
+        let vec_r: Vec<_> = CsvRow::new(row, ',', false)
+            .mode(FieldMode::Literal)
+            .collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_csv_with_quoted_field_containing_quote() {
-        let row = r#"january,february,"The ""Coder"" Man",march,april"#;
+    fn strip_quotes_mode_leaves_escapes_and_unquoted_fields() {
+        let row = r#"a""b, "c""" ,"d,e""#;
 
-        let csv = CsvRow::new(row, ',', false);
+        let csv = CsvRow::new(row, ',', true)
+            .mode(FieldMode::StripQuotes)
+            .trim(true);
 
-        let vec_t: Vec<_> = vec!["january", "february", "The \"Coder\" Man", "march", "april"];
+        let vec_t: Vec<_> = vec![r#"a""b"#, r#"c"""#, "d,e"];
         let vec_r: Vec<_> = csv.collect();
 
+        assert_eq!(vec_t[..], vec_r[..]);
+
+        let mut scratch = String::new();
+        let mut csv = CsvRow::new(row, ',', false).mode(FieldMode::StripQuotes);
+        let (_, field) = csv.scan_field().unwrap();
+
+        assert_eq!(r#"a""b"#, csv.unescape_field_into(field, &mut scratch))
+    }
+
+    #[test]
+    fn keep_quotes_borrows_fields_without_escapes() {
+        let mut csv = CsvRow::new(r#" "a" ,"b""c""#, ',', false)
+            .trim(true)
+            .mode(FieldMode::KeepQuotes);
+
+        assert!(matches!(csv.next(), Some(Cow::Borrowed(r#""a""#))));
+        assert!(matches!(csv.next(), Some(Cow::Owned(_))));
+    }
+
+    #[test]
+    fn subfields_split_a_quoted_field() {
+        let row = r#"x,y,"a;b;c""#;
+
+        let field = CsvRow::new(row, ',', false).nth(2).unwrap();
+
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = subfields(&field, ';').collect();
+
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_csv_with_orphaned_quote() {
-        let row = r#"january,feb"ruary,march,april"#;
+    fn subfields_keep_quoted_sub_delimiters() {
+        let row = r#"x,"a;""b;c"""#;
 
-        let csv = CsvRow::new(row, ',', false);
+        let field = CsvRow::new(row, ',', false).nth(1).unwrap();
 
-        let vec_t: Vec<_> = vec!["january", "feb\"ruary", "march", "april"];
-        let vec_r: Vec<_> = csv.collect();
+        let vec_t: Vec<_> = vec!["a", "b;c"];
+        let vec_r: Vec<_> = subfields(&field, ';').collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_csv_with_premature_close_quote() {
-        let row = r#"january,"feb"ruary,march,april"#;
+    fn empty_line_yields_no_fields_by_default() {
+        let csv = CsvRow::new("", ',', false).empty_line_yields(EmptyLine::NoFields);
 
-        let csv = CsvRow::new(row, ',', false);
+        assert_eq!(0, csv.clone().count());
+        assert_eq!((0, Some(0)), csv.size_hint());
+        assert_eq!(0, CsvRow::new("", ',', false).count())
+    }
 
-        let vec_t: Vec<_> = vec!["january", "\"feb\"ruary,march,april"];
+    #[test]
+    fn empty_line_yields_one_empty_field() {
+        let csv = CsvRow::new("", ',', false).empty_line_yields(EmptyLine::OneField);
+
+        let vec_t: Vec<_> = vec![""];
+        let vec_r: Vec<_> = csv.clone().collect();
+        let vec_b: Vec<_> = csv.clone().rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..]);
+        assert_eq!(1, csv.count())
+    }
+
+    #[test]
+    fn can_parse_csv_with_custom_escape_char() {
+        let row = r#""a^"b",c^^d,"e^^""#;
+
+        let csv = CsvRow::new(row, ',', false).escape_style(EscapeStyle::Char('^'));
+
+        let vec_t: Vec<_> = vec!["a\"b", "c^d", "e^"];
+        let vec_r: Vec<_> = csv.clone().collect();
+        let mut vec_b: Vec<_> = csv.rev().collect();
+        vec_b.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..])
+    }
+
+    #[test]
+    fn custom_escape_char_with_custom_quote() {
+        let row = "'a^'b,c',d";
+
+        let csv = CsvRow::with_quote(row, ',', false, '\'').escape_style(EscapeStyle::Char('^'));
+
+        let vec_t: Vec<_> = vec!["a'b,c", "d"];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_csv_with_empty_quoted_field() {
-        let row = r#"january,"""#;
+    fn can_parse_emoji_delimiter() {
+        let row = "a🦙b🦙c";
+
+        let csv = CsvRow::new(row, '🦙', false);
+
+        let vec_t: Vec<_> = vec!["a", "b", "c"];
+        let vec_r: Vec<_> = csv.clone().collect();
+        let mut vec_b: Vec<_> = csv.clone().rev().collect();
+        vec_b.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..]);
+        assert_eq!(3, csv.clone().count());
+        assert_eq!(3, CsvRow::field_count(row, '🦙'));
+
+        let mut csv = csv;
+        csv.next();
+
+        assert_eq!(5, csv.cursor().byte);
+        assert_eq!("b🦙c", csv.remainder())
+    }
+
+    #[test]
+    fn can_parse_trailing_emoji_delimiter() {
+        let row = "a🦙\"b🦙\"🦙";
+
+        let csv = CsvRow::new(row, '🦙', false);
+
+        let vec_t: Vec<_> = vec!["a", "b🦙", ""];
+        let vec_r: Vec<_> = csv.clone().collect();
+        let mut vec_b: Vec<_> = csv.clone().rev().collect();
+        vec_b.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..]);
+
+        let spans: Vec<_> = csv.spanned().map(|(span, _)| span).collect();
+
+        assert_eq!(vec![0..1, 5..12, 16..16], spans)
+    }
+
+    #[test]
+    fn can_parse_quoted_single_multibyte_char() {
+        let row = r#""é",🦙,"🦙""#;
 
         let csv = CsvRow::new(row, ',', false);
 
-        let vec_t: Vec<_> = vec!["january", ""];
+        let vec_t: Vec<_> = vec!["é", "🦙", "🦙"];
+        let vec_r: Vec<_> = csv.clone().collect();
+        let mut vec_b: Vec<_> = csv.rev().collect();
+        vec_b.reverse();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..])
+    }
+
+    #[test]
+    fn does_not_panic_on_degenerate_multibyte_quotes() {
+        let rows = [
+            r#"é""#, r#""é"#, r#"""é"#, "«", "«é", "é«", "««", "«««", "«é«",
+        ];
+
+        for row in rows {
+            for quote in ['"', '«'] {
+                let csv = CsvRow::with_quote(row, ',', false, quote).trim(true);
+
+                let vec_r: Vec<_> = csv.clone().collect();
+                let mut vec_b: Vec<_> = csv.clone().rev().collect();
+                vec_b.reverse();
+
+                assert_eq!(vec_r[..], vec_b[..], "{row:?}");
+                assert_eq!(vec_r.len(), csv.clone().try_fields().count());
+            }
+        }
+
+        let csv = CsvRow::with_quote("«é«,«", ',', false, '«');
+        let vec_t: Vec<_> = vec!["é", "«"];
         let vec_r: Vec<_> = csv.collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn can_parse_simple_csv_with_spaces() {
-        let row = r#"january, "february", march, april"#;
+    fn dialect_reports_configuration() {
+        let csv =
+            CsvRow::with_str_delimiter("a||b", "||", false).escape_style(EscapeStyle::Backslash);
 
-        let csv = CsvRow::new(row, ',', false);
+        let dialect = Dialect {
+            delimiter: Delimiter::Str("||"),
+            quote: Some('"'),
+            escape_style: EscapeStyle::Backslash,
+            terminator: None,
+        };
 
-        let vec_t: Vec<_> = vec!["january", " \"february\"", " march", " april"];
-        let vec_r: Vec<_> = csv.collect();
+        assert_eq!(dialect, csv.dialect());
+        assert_eq!(None, CsvRow::unquoted("a\tb", '\t').dialect().quote)
+    }
+
+    #[test]
+    fn quoted_fields_reports_quoting() {
+        let row = r#"a,"b",c"#;
+
+        let csv = CsvRow::new(row, ',', false).quoted_fields();
+        let vec_t: Vec<_> = vec![false, true, false];
+        let vec_r: Vec<_> = csv.map(|(quoted, _)| quoted).collect();
 
         assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn escapes_complex_string() {
-        let expression = "this is a \"test\", of course...";
-        let result = escape(&expression, ',');
+    fn quoted_fields_reports_trimmed_quoting_from_the_back() {
+        let row = r#" "a" ,"",b"#;
 
-        assert_eq!("\"this is a \"\"test\"\", of course...\"", result)
+        let csv = CsvRow::new(row, ',', false).trim(true).quoted_fields();
+        let vec_t: Vec<_> = vec![(false, "b".into()), (true, "".into()), (true, "a".into())];
+        let vec_r: Vec<_> = csv.rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
     }
 
     #[test]
-    fn does_not_escape_simple_string() {
-        let expression = "chupacabra";
-        let result = escape(&expression, ',');
+    fn header_index_finds_present_column() {
+        let header = r#"id,name,"Städte, Länder",name"#;
 
-        assert_eq!(expression, result)
+        assert_eq!(Some(0), header_index(header, "id", ','));
+        assert_eq!(Some(1), header_index(header, "name", ','));
+        assert_eq!(Some(2), header_index(header, "Städte, Länder", ','));
+        assert_eq!(
+            Some(2),
+            header_index_ignore_case(header, "STÄDTE, länder", ',')
+        );
+    }
+
+    #[test]
+    fn normalized_headers_match_padded_mixed_case_names() {
+        let header = "id, First Name ,\"  LAST name\",Städte";
+
+        let vec_t: Vec<_> = vec![
+            (0, "id".to_string()),
+            (1, "first name".to_string()),
+            (2, "last name".to_string()),
+            (3, "städte".to_string()),
+        ];
+        let vec_r: Vec<_> = normalized_headers(header, ',').collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(
+            Some(0),
+            normalized_headers(" First Name ", ',')
+                .find(|(_, key)| key == "first name")
+                .map(|(i, _)| i)
+        )
+    }
+
+    #[test]
+    fn bytes_consumed_increases_with_each_field() {
+        let row = r#"january,"leap, day",🦙,"#;
+
+        let mut csv = CsvRow::new(row, ',', false);
+        let mut consumed = vec![csv.bytes_consumed()];
+
+        while csv.next().is_some() {
+            consumed.push(csv.bytes_consumed());
+            assert_eq!(csv.cursor().byte, csv.bytes_consumed());
+        }
+
+        assert_eq!(vec![0, 8, 20, 25, 25], consumed);
+        assert!(consumed.windows(2).all(|w| w[0] <= w[1]));
+        assert!(consumed.iter().all(|&c| c <= row.len()));
+    }
+
+    #[test]
+    fn header_index_is_none_for_absent_column() {
+        let header = "id,name,city";
+
+        assert_eq!(None, header_index(header, "Name", ','));
+        assert_eq!(None, header_index(header, "country", ','));
+        assert_eq!(None, header_index_ignore_case(header, "country", ','));
+    }
+
+    #[test]
+    fn escape_with_terminator_quotes_terminator() {
+        assert_eq!("\"a\rb\"", escape_with_terminator("a\rb", ',', '\r'));
+        assert_eq!("\"a|b\"", escape_with_terminator("a|b", ',', '|'));
+        assert_eq!("\"a\nb\"", escape_with_terminator("a\nb", ',', '|'));
+        assert_eq!("ab", escape_with_terminator("ab", ',', '\r'));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn can_parse_long_single_field_line() {
+        let row = " single ".repeat(10_000);
+
+        let vec_t: Vec<_> = vec![row.as_str()];
+        let vec_r: Vec<_> = CsvRow::new(&row, ',', false).collect();
+        let vec_b: Vec<_> = CsvRow::new(&row, ',', false).rev().collect();
+
+        assert_eq!(vec_t[..], vec_r[..]);
+        assert_eq!(vec_t[..], vec_b[..]);
+
+        let mut csv = CsvRow::new(&row, ',', false).trim(true);
+
+        assert_eq!(Some(row.trim().into()), csv.next());
+        assert_eq!(
+            Position {
+                field: 2,
+                byte: row.len()
+            },
+            csv.cursor()
+        );
+        assert_eq!(None, csv.next());
+    }
+
+    #[test]
+    fn relaxed_quotes_match_python_csv() {
+        // Each row with the fields `csv.reader` yields for it with the default dialect
+        let rows: [(&str, &[&str]); 15] = [
+            (r#"a,b"c,d"#, &["a", r#"b"c"#, "d"]),
+            (r#""feb"ruary,march"#, &["february", "march"]),
+            (r#""a""b",c"#, &[r#"a"b"#, "c"]),
+            (r#"a,"b,c"#, &["a", "b,c"]),
+            (r#"x, "y",z"#, &["x", r#" "y""#, "z"]),
+            (r#""a"b"c",d"#, &[r#"ab"c""#, "d"]),
+            (r#""",x"#, &["", "x"]),
+            (r#""a" ,b"#, &["a ", "b"]),
+            (r#"a""b,c"#, &[r#"a""b"#, "c"]),
+            (r#"""#, &[""]),
+            (r#"a,""#, &["a", ""]),
+            (r#""a"",b"#, &[r#"a",b"#]),
+            (r#""""""#, &["\""]),
+            (r#"a,"b"c"d,e"#, &["a", r#"bc"d"#, "e"]),
+            (r#""a,b"c,d"#, &["a,bc", "d"]),
+        ];
+
+        for (row, fields) in rows {
+            let csv = CsvRow::new(row, ',', false).quote_mode(QuoteMode::Relaxed);
+            let vec_r: Vec<_> = csv.clone().collect();
+            let mut vec_b: Vec<_> = csv.rev().collect();
+            vec_b.reverse();
+
+            assert_eq!(fields[..], vec_r[..], "{row:?}");
+            assert_eq!(fields[..], vec_b[..], "{row:?}");
+        }
+    }
+
+    #[test]
+    fn relaxed_quotes_match_python_csv_escapechar() {
+        // As with `csv.reader(..., escapechar="\\", doublequote=False)`
+        let rows: [(&str, &[&str]); 4] = [
+            (r#""a\"b",c"#, &[r#"a"b"#, "c"]),
+            (r#"a\,b,c"#, &["a,b", "c"]),
+            (r#""a\,b",c"#, &["a,b", "c"]),
+            (r#""a"\b,c"#, &["ab", "c"]),
+        ];
+
+        for (row, fields) in rows {
+            let csv = CsvRow::new(row, ',', false)
+                .quote_mode(QuoteMode::Relaxed)
+                .escape_style(EscapeStyle::Backslash);
+            let vec_r: Vec<_> = csv.collect();
+
+            assert_eq!(fields[..], vec_r[..], "{row:?}");
+        }
+    }
+
+    #[test]
+    fn relaxed_quotes_skip_initial_space() {
+        // As with `csv.reader(..., skipinitialspace=True)`
+        let csv = CsvRow::new(r#"x, "y,z",  w"#, ',', false)
+            .quote_mode(QuoteMode::Relaxed)
+            .skip_space_before_quote(true);
+        let vec_t: Vec<_> = vec!["x", "y,z", "w"];
+        let vec_r: Vec<_> = csv.collect();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn relaxed_quotes_report_no_quoting_errors() {
+        let row = r#"january,"feb"ruary,"mar"#;
+
+        let csv = CsvRow::new(row, ',', false).quote_mode(QuoteMode::Relaxed);
+        let vec_r: Result<Vec<_>, _> = csv.strict(true).try_fields().collect();
+
+        assert_eq!(
+            Ok(vec!["january".into(), "february".into(), "mar".into()]),
+            vec_r
+        );
+
+        let mut scratch = String::new();
+        let mut csv = CsvRow::new(row, ',', false).quote_mode(QuoteMode::Relaxed);
+        let (_, field) = csv.scan_field().unwrap();
+        assert_eq!("january", csv.unescape_field_into(field, &mut scratch));
+        let (_, field) = csv.scan_field().unwrap();
+        assert_eq!("february", csv.unescape_field_into(field, &mut scratch));
+    }
+}
@@ -0,0 +1,69 @@
+use rayon::prelude::*;
+
+use crate::document::{parse_record, record_len};
+
+/// Parses the records of `text` in parallel, returning them in their original order.
+///
+/// The boundaries between records are found first, respecting newlines within quoted fields, and then the
+/// records are split into fields on the rayon thread pool. The result is the same as collecting a
+/// `CsvDocument` over `text`. Requires the `rayon` feature.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the records, separated by newlines
+/// * `delimiter` - A char that represents the delimiter
+/// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::par_records;
+/// let text = "a,\"b\nc\"\nd,e";
+///
+/// assert_eq!(par_records(text, ',', false), vec![vec!["a", "b\nc"], vec!["d", "e"]])
+/// ```
+pub fn par_records(text: &str, delimiter: char, literal: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let (record, next) = rest.split_at(record_len(rest, delimiter, '\n'));
+        records.push(record);
+        rest = next;
+    }
+
+    records
+        .into_par_iter()
+        .map(|record| {
+            let (fields, _, _) = parse_record(record, delimiter, '\n', literal);
+            fields.into_iter().map(|f| f.into_owned()).collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::CsvDocument;
+
+    #[test]
+    fn parallel_and_sequential_parsing_agree() {
+        let text = (0..2_000)
+            .map(|i| format!("{i},\"leap,\n day {i}\",,\"The \"\"Coder\"\" Man\"\n\n"))
+            .collect::<String>();
+
+        for literal in [false, true] {
+            let sequential: Vec<Vec<String>> = CsvDocument::new(&text, ',', literal)
+                .map(|record| record.into_iter().map(|f| f.into_owned()).collect())
+                .collect();
+
+            assert_eq!(sequential, par_records(&text, ',', literal));
+        }
+    }
+
+    #[test]
+    fn parses_empty_text() {
+        assert!(par_records("", ',', false).is_empty())
+    }
+}
@@ -0,0 +1,396 @@
+use std::io::{self, Read};
+use std::str::{self, Utf8Error};
+
+use crate::document::parse_record;
+
+/// The number of bytes a CsvReader requests from its source at a time, unless told otherwise
+pub(crate) const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// An iterator over the records read from a source of UTF-8 text, such as a file.
+///
+/// Only as much of the source as is needed to complete the next record is kept in memory. Records are
+/// separated by `\n` and split into fields as with `CsvDocument`.
+pub struct CsvReader<R> {
+    reader: R,
+    pub delimiter: char,
+    pub literal: bool,
+    records: RecordBuffer,
+}
+
+impl<R: Read> CsvReader<R> {
+    /// Creates a new CsvReader
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A source that holds the records, separated by newlines
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::CsvReader;
+    /// let source = "a,\"b\nc\"\nd,e".as_bytes();
+    /// let reader = CsvReader::new(source, ',', false);
+    /// let vec_r: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+    ///
+    /// assert_eq!(vec_r, vec![vec!["a", "b\nc"], vec!["d", "e"]])
+    /// ```
+    pub fn new(reader: R, delimiter: char, literal: bool) -> CsvReader<R> {
+        CsvReader::with_capacity(DEFAULT_CAPACITY, reader, delimiter, literal)
+    }
+
+    /// Creates a new CsvReader that requests `capacity` bytes from `reader` at a time
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of bytes to read at a time
+    /// * `reader` - A source that holds the records, separated by newlines
+    /// * `delimiter` - A char that represents the delimiter
+    /// * `literal` - A bool that indicates whether the parsed fields should be unescaped or read literally. If true, enclosing and escaping quotes will be included in the results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(
+        capacity: usize,
+        reader: R,
+        delimiter: char,
+        literal: bool,
+    ) -> CsvReader<R> {
+        CsvReader {
+            reader,
+            delimiter,
+            literal,
+            records: RecordBuffer::new(capacity),
+        }
+    }
+
+    /// Reads up to `capacity` more bytes into the buffer
+    fn fill_buffer(&mut self) -> io::Result<()> {
+        loop {
+            let read = self.reader.read(self.records.unfilled());
+            self.records.filled(read.as_ref().ok().copied());
+
+            match read {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                result => return result.map(|_| ()),
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for CsvReader<R> {
+    type Item = io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next_record(self.delimiter, self.literal) {
+                Some(next) => return next,
+                None => {
+                    if let Err(e) = self.fill_buffer() {
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The text read from a source that has not yet been parsed into records
+///
+/// Each byte is decoded once and each record parsed once. The end of an open record is searched for from where the
+/// last search stopped, so a record that spans many reads, such as one with an unclosed quote, takes linear time.
+pub(crate) struct RecordBuffer {
+    capacity: usize,
+    /// The bytes of the last read, after any char that the read before it split
+    bytes: Vec<u8>,
+    filled: usize,
+    /// The text decoded so far, of which the records before `start` have been taken
+    text: String,
+    start: usize,
+    /// Where the search for the end of the record at `start` resumes
+    scan: RecordScan,
+    invalid: Option<Utf8Error>,
+    eof: bool,
+}
+
+/// How far the search for the end of a record has got
+#[derive(Clone, Copy)]
+struct RecordScan {
+    /// The byte offset within the text at which the search resumes
+    pos: usize,
+    /// The state of the field being scanned at `pos`
+    field: FieldScan,
+}
+
+/// The state of the scan of a field, as `CsvRow` scans it with the dialect of a CsvReader
+#[derive(Clone, Copy)]
+enum FieldScan {
+    /// No char of the field has been read
+    Start,
+    Unquoted,
+    /// Within a quoted field, whether the last quote opened rather than closed the quotes, and whether the last
+    /// char was a quote
+    Quoted {
+        in_quotes: bool,
+        after_quote: bool,
+    },
+}
+
+impl RecordScan {
+    /// Returns a scan from the start of a record at byte offset `pos`
+    fn at(pos: usize) -> RecordScan {
+        RecordScan {
+            pos,
+            field: FieldScan::Start,
+        }
+    }
+}
+
+impl RecordBuffer {
+    /// Creates a new RecordBuffer that grows by `capacity` bytes for each read
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub(crate) fn new(capacity: usize) -> RecordBuffer {
+        assert!(capacity > 0, "capacity must not be zero");
+
+        RecordBuffer {
+            capacity,
+            bytes: Vec::new(),
+            filled: 0,
+            text: String::new(),
+            start: 0,
+            scan: RecordScan::at(0),
+            invalid: None,
+            eof: false,
+        }
+    }
+
+    /// Returns `capacity` bytes of space after the buffered bytes for the next read to fill
+    ///
+    /// The records that have been taken are dropped from the text first, so it only ever holds the open one.
+    pub(crate) fn unfilled(&mut self) -> &mut [u8] {
+        if self.start > 0 {
+            self.text.drain(..self.start);
+            self.scan.pos -= self.start;
+            self.start = 0;
+        }
+
+        self.bytes.resize(self.filled + self.capacity, 0);
+        &mut self.bytes[self.filled..]
+    }
+
+    /// Keeps the `read` bytes that a read into `unfilled()` returned, noting when the source is exhausted
+    ///
+    /// `read` is `None` if the read failed or is still pending, so nothing was read.
+    pub(crate) fn filled(&mut self, read: Option<usize>) {
+        self.filled += read.unwrap_or(0);
+        self.bytes.truncate(self.filled);
+        self.eof = read == Some(0);
+
+        // A multi-byte character may be split across reads, so only decode the complete ones
+        let valid = match str::from_utf8(&self.bytes) {
+            Ok(text) => {
+                self.text.push_str(text);
+                self.filled
+            }
+            Err(e) => {
+                let valid = e.valid_up_to();
+                self.text
+                    .push_str(str::from_utf8(&self.bytes[..valid]).unwrap());
+
+                if e.error_len().is_some() || self.eof {
+                    self.invalid = Some(e);
+                }
+                valid
+            }
+        };
+
+        self.bytes.drain(..valid);
+        self.filled -= valid;
+    }
+
+    /// Takes the next record from the buffer
+    ///
+    /// Returns `None` if more bytes must be read to complete the record, or `Some(None)` once the source is
+    /// exhausted and every record has been taken.
+    pub(crate) fn next_record(
+        &mut self,
+        delimiter: char,
+        literal: bool,
+    ) -> Option<Option<io::Result<Vec<String>>>> {
+        let end = match self.find_record_end(delimiter) {
+            Some(end) => end,
+            None if self.invalid.is_some() => {
+                let e = self.invalid.take().unwrap();
+                self.text.clear();
+                self.bytes.clear();
+                self.filled = 0;
+                self.start = 0;
+                self.scan = RecordScan::at(0);
+
+                return Some(Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))));
+            }
+            None if self.eof && self.start < self.text.len() => self.text.len(),
+            None => {
+                return match self.eof {
+                    true => Some(None),
+                    false => None,
+                }
+            }
+        };
+
+        let (record, _, _) = parse_record(&self.text[self.start..end], delimiter, '\n', literal);
+        let record = record.into_iter().map(|f| f.into_owned()).collect();
+        self.start = end;
+        self.scan = RecordScan::at(end);
+
+        Some(Some(Ok(record)))
+    }
+
+    /// Searches on from where the last search stopped for the end of the record at `start`, including its `\n`
+    ///
+    /// Returns `None` if the text read so far does not complete the record.
+    fn find_record_end(&mut self, delimiter: char) -> Option<usize> {
+        let RecordScan { pos, mut field } = self.scan;
+
+        for (i, c) in self.text[pos..].char_indices() {
+            let i = pos + i;
+
+            // A quoted field only ends at a delimiter right after the quote that closes it
+            let ends_field = match field {
+                FieldScan::Quoted {
+                    in_quotes,
+                    after_quote,
+                } => !in_quotes && after_quote,
+                _ => true,
+            };
+
+            if ends_field {
+                match c {
+                    '\n' => return Some(i + 1),
+                    '\r' if self.text[i + 1..].starts_with('\n') => return Some(i + 2),
+                    // The `\n` that would end the record at this CR has yet to be read
+                    '\r' if i + 1 == self.text.len() => {
+                        self.scan = RecordScan { pos: i, field };
+                        return None;
+                    }
+                    c if c == delimiter => {
+                        field = FieldScan::Start;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            field = match field {
+                FieldScan::Start if c == '"' => FieldScan::Quoted {
+                    in_quotes: true,
+                    after_quote: true,
+                },
+                FieldScan::Start | FieldScan::Unquoted => FieldScan::Unquoted,
+                FieldScan::Quoted { in_quotes, .. } => FieldScan::Quoted {
+                    in_quotes: in_quotes != (c == '"'),
+                    after_quote: c == '"',
+                },
+            };
+        }
+
+        self.scan = RecordScan {
+            pos: self.text.len(),
+            field,
+        };
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn can_read_records_with_newline_straddling_buffer() {
+        let source = Cursor::new("january,\"leap\nday\",march\napril,may\n".as_bytes());
+
+        let reader = CsvReader::with_capacity(4, source, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["january", "leap\nday", "march"], vec!["april", "may"]];
+        let vec_r: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_read_multibyte_chars_straddling_buffer() {
+        let source = Cursor::new("è,\"é\n\",ü".as_bytes());
+
+        let reader = CsvReader::with_capacity(1, source, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["è", "é\n", "ü"]];
+        let vec_r: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_read_literal_records() {
+        let source = Cursor::new("a,\"b\"\"c\"\n\nd".as_bytes());
+
+        let reader = CsvReader::with_capacity(3, source, ',', true);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "\"b\"\"c\""], vec![], vec!["d"]];
+        let vec_r: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn can_read_crlf_straddling_buffer() {
+        let source = Cursor::new("a,\"b\"\r\nc\r\n\r\nd\r".as_bytes());
+
+        let reader = CsvReader::with_capacity(1, source, ',', false);
+
+        let vec_t: Vec<Vec<_>> = vec![vec!["a", "b"], vec!["c"], vec![], vec!["d\r"]];
+        let vec_r: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+
+        assert_eq!(vec_t[..], vec_r[..])
+    }
+
+    #[test]
+    fn unclosed_quote_runs_to_end_of_source() {
+        let mut source = String::from("a,\"open\n");
+        source.push_str(&"x,\"y\"\"\",z\n".repeat(1000));
+
+        let reader = CsvReader::with_capacity(16, Cursor::new(source.as_bytes()), ',', true);
+
+        let vec_r: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+
+        assert_eq!(vec![vec!["a".to_string(), source[2..].to_string()]], vec_r)
+    }
+
+    #[test]
+    fn can_read_empty_source() {
+        let reader = CsvReader::new(Cursor::new(Vec::new()), ',', false);
+
+        assert_eq!(reader.count(), 0)
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_error() {
+        let source = Cursor::new(b"a,b\n\xFF,c\n".to_vec());
+
+        let mut reader = CsvReader::new(source, ',', false);
+
+        assert_eq!(reader.next().unwrap().unwrap(), vec!["a", "b"]);
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
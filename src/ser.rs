@@ -0,0 +1,439 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use serde::ser::{self, Impossible, Serialize, Serializer};
+
+use crate::{write_row, CsvError};
+
+/// Serializes `value`, such as a struct or tuple, into a line holding its fields in order.
+///
+/// Each field is escaped as it would be by `escape()`, and `None` is written as an empty field. A value that
+/// holds a nested struct, sequence or map cannot be written to a flat row, so it is an error. Requires the
+/// `serde` feature.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Record {
+///     id: u32,
+///     name: String,
+///     weight: Option<f64>,
+/// }
+///
+/// let record = Record { id: 1, name: "Smith, J".into(), weight: None };
+///
+/// assert_eq!("1,\"Smith, J\",", csvrow::to_row(&record, ',').unwrap());
+/// ```
+pub fn to_row<T: Serialize + ?Sized>(value: &T, delimiter: char) -> Result<String, CsvError> {
+    let mut serializer = RowSerializer { fields: Vec::new() };
+
+    value.serialize(&mut serializer)?;
+
+    Ok(write_row(&serializer.fields, delimiter))
+}
+
+impl ser::Error for CsvError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CsvError::Serialize(msg.to_string())
+    }
+}
+
+/// Returns the error for a value that cannot be written as a single field
+fn nested(kind: &str) -> CsvError {
+    ser::Error::custom(format_args!("cannot write a nested {kind} to a field"))
+}
+
+/// Serializes a whole row, writing each element of a struct, tuple or sequence as a field
+struct RowSerializer {
+    fields: Vec<String>,
+}
+
+impl RowSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        self.fields.push(value.serialize(FieldSerializer)?);
+        Ok(())
+    }
+}
+
+/// Writes a value that is not a struct, tuple or sequence as a row of a single field
+macro_rules! serialize_single_field {
+    ($($method:ident($type:ty)),*) => {
+        $(
+            fn $method(self, v: $type) -> Result<(), CsvError> {
+                self.push(&v)
+            }
+        )*
+    };
+}
+
+impl Serializer for &mut RowSerializer {
+    type Ok = ();
+    type Error = CsvError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Impossible<(), CsvError>;
+    type SerializeMap = Impossible<(), CsvError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), CsvError>;
+
+    serialize_single_field! {
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_i128(i128), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_u128(u128), serialize_f32(f32), serialize_f64(f64), serialize_char(char), serialize_str(&str),
+        serialize_bytes(&[u8])
+    }
+
+    fn serialize_none(self) -> Result<(), CsvError> {
+        self.push(&None::<()>)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CsvError> {
+        self.push(&())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), CsvError> {
+        self.push(&())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), CsvError> {
+        self.push(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), CsvError> {
+        Err(nested("enum variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, CsvError> {
+        Err(nested("enum variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, CsvError> {
+        Err(nested("map"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, CsvError> {
+        Err(nested("enum variant"))
+    }
+}
+
+impl ser::SerializeSeq for &mut RowSerializer {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut RowSerializer {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut RowSerializer {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut RowSerializer {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), CsvError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+/// Serializes a single value into the unescaped text of its field
+struct FieldSerializer;
+
+/// Writes a value as its `Display` text
+macro_rules! serialize_display {
+    ($($method:ident($type:ty)),*) => {
+        $(
+            fn $method(self, v: $type) -> Result<String, CsvError> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+impl Serializer for FieldSerializer {
+    type Ok = String;
+    type Error = CsvError;
+    type SerializeSeq = Impossible<String, CsvError>;
+    type SerializeTuple = Impossible<String, CsvError>;
+    type SerializeTupleStruct = Impossible<String, CsvError>;
+    type SerializeTupleVariant = Impossible<String, CsvError>;
+    type SerializeMap = Impossible<String, CsvError>;
+    type SerializeStruct = Impossible<String, CsvError>;
+    type SerializeStructVariant = Impossible<String, CsvError>;
+
+    serialize_display! {
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_i128(i128), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_u128(u128), serialize_f32(f32), serialize_f64(f64), serialize_char(char), serialize_str(&str)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<String, CsvError> {
+        match core::str::from_utf8(v) {
+            Ok(text) => Ok(text.into()),
+            Err(e) => Err(ser::Error::custom(format_args!(
+                "cannot write bytes to a field: {e}"
+            ))),
+        }
+    }
+
+    fn serialize_none(self) -> Result<String, CsvError> {
+        Ok(String::new())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String, CsvError> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, CsvError> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<String, CsvError> {
+        Ok(variant.into())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, CsvError> {
+        Err(nested("enum variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, CsvError> {
+        Err(nested("sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, CsvError> {
+        Err(nested("tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, CsvError> {
+        Err(nested("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, CsvError> {
+        Err(nested("enum variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, CsvError> {
+        Err(nested("map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, CsvError> {
+        Err(nested("struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, CsvError> {
+        Err(nested("enum variant"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::from_row;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct R {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn serializes_struct_quoting_field_with_comma() {
+        let r = R {
+            id: 7,
+            name: "Smith, J".into(),
+        };
+
+        assert_eq!(Ok("7,\"Smith, J\"".into()), to_row(&r, ','));
+    }
+
+    #[test]
+    fn serialized_row_deserializes_to_same_value() {
+        let r = R {
+            id: 7,
+            name: "The \"Coder\" Man".into(),
+        };
+
+        let line = to_row(&r, ';').unwrap();
+
+        assert_eq!(Ok(r), from_row(&line, ';'));
+    }
+
+    #[test]
+    fn serializes_tuple_with_options_and_enums() {
+        #[derive(Serialize)]
+        enum Month {
+            February,
+        }
+
+        let record = ("a", None::<u32>, Some(1.5), Month::February);
+
+        assert_eq!(Ok("a,,1.5,February".into()), to_row(&record, ','));
+    }
+
+    #[test]
+    fn nested_values_are_an_error() {
+        #[derive(Serialize)]
+        struct Outer {
+            id: u32,
+            inner: R,
+        }
+
+        let outer = Outer {
+            id: 1,
+            inner: R {
+                id: 2,
+                name: "b".into(),
+            },
+        };
+
+        assert_eq!(
+            Err(CsvError::Serialize(
+                "cannot write a nested struct to a field".into()
+            )),
+            to_row(&outer, ',')
+        );
+        assert!(to_row(&(1, vec![2, 3]), ',').is_err());
+    }
+}
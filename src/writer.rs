@@ -0,0 +1,461 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::slice;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{escape, escape_into, CsvRow};
+#[cfg(feature = "std")]
+use crate::{escape_with_terminator, Delimiter, Dialect, EscapeStyle};
+
+/// Returns a line holding `fields` separated by `delimiter`, escaping each field as `escape()` would.
+///
+/// A row of a single empty field is written as `""`, since an empty line holds no fields at all.
+///
+/// # Arguments
+///
+/// * `fields` - The values to write, in order
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::write_row;
+/// let line = write_row(["rust", "is", "awesome, \"truly\""], ',');
+///
+/// assert_eq!("rust,is,\"awesome, \"\"truly\"\"\"", line)
+/// ```
+pub fn write_row<I>(fields: I, delimiter: char) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut line = String::new();
+    let mut count = 0;
+
+    for field in fields {
+        if count > 0 {
+            line.push(delimiter);
+        }
+
+        escape_into(field.as_ref(), delimiter, &mut line);
+        count += 1;
+    }
+
+    if count == 1 && line.is_empty() {
+        line.push_str("\"\"");
+    }
+
+    line
+}
+
+/// Joins `fields` into a line separated by `delimiter`, the inverse of parsing it with `CsvRow::new`.
+///
+/// Each field is escaped as `escape()` would escape it, so the line is RFC-4180 compliant. No delimiter follows
+/// the last field, and no fields at all join to an empty line. This is the same line that `write_row()` writes.
+///
+/// # Arguments
+///
+/// * `fields` - The values to join, in order
+/// * `delimiter` - A char that represents the delimiter
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{join, CsvRow};
+/// let line = join(["a", "b,c", ""], ',');
+/// let vec_r: Vec<_> = CsvRow::new(&line, ',', false).collect();
+///
+/// assert_eq!("a,\"b,c\",", line);
+/// assert_eq!(vec!["a", "b,c", ""], vec_r)
+/// ```
+pub fn join(fields: impl IntoIterator<Item = impl AsRef<str>>, delimiter: char) -> String {
+    write_row(fields, delimiter)
+}
+
+/// A row of fields that is displayed as a line, escaping each field as `escape()` would
+///
+/// The line is written exactly as `write_row()` would write it.
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::{CsvRow, Row};
+/// let row = Row::new(CsvRow::new(r#"a,"b,c""#, ',', false).collect(), ';');
+///
+/// assert_eq!("a;b,c", row.to_string())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row<'a> {
+    pub fields: Vec<Cow<'a, str>>,
+    pub delimiter: char,
+}
+
+impl<'a> Row<'a> {
+    /// Creates a new Row
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The values of the row, in order
+    /// * `delimiter` - A char that represents the delimiter to write between fields
+    pub fn new(fields: Vec<Cow<'a, str>>, delimiter: char) -> Row<'a> {
+        Row { fields, delimiter }
+    }
+}
+
+impl fmt::Display for Row<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let [field] = &self.fields[..] {
+            if field.is_empty() {
+                return f.write_str("\"\"");
+            }
+        }
+
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.delimiter)?;
+            }
+
+            f.write_str(&escape(field, self.delimiter))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A record of owned fields, for moving already split values between parsing and writing
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::Record;
+/// let record = Record::parse(r#"a,"b,c""#, ',');
+///
+/// assert_eq!(vec!["a", "b,c"], record.fields);
+/// assert_eq!("a;b,c", record.to_line(';'))
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Record {
+    pub fields: Vec<String>,
+}
+
+impl Record {
+    /// Creates a new Record from the fields of `line`, as `CsvRow::new` would parse them
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A string slice that holds the delimited fields
+    /// * `delimiter` - A char that represents the delimiter
+    pub fn parse(line: &str, delimiter: char) -> Record {
+        Record {
+            fields: CsvRow::new(line, delimiter, false).into_owned_vec(),
+        }
+    }
+
+    /// Returns the fields joined into a line by `delimiter`, escaping each field as `escape()` would
+    ///
+    /// The line is written exactly as `write_row()` would write it.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - A char that represents the delimiter
+    pub fn to_line(&self, delimiter: char) -> String {
+        write_row(&self.fields, delimiter)
+    }
+}
+
+impl From<Vec<String>> for Record {
+    fn from(fields: Vec<String>) -> Record {
+        Record { fields }
+    }
+}
+
+impl IntoIterator for Record {
+    type Item = String;
+    type IntoIter = vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Record {
+    type Item = &'a String;
+    type IntoIter = slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.iter()
+    }
+}
+
+/// A writer of records to a sink, such as a file, one field at a time
+///
+/// Each field is escaped as `escape()` would escape it and written straight to the sink, so no line is built in
+/// memory. Records end with `\n`, or the terminator set with `terminator`, and as with `write_row()`, a record of a
+/// single empty field is written as `""`.
+/// Nothing is flushed until `flush()` is called, so a buffered sink writes in as few calls as it can. Requires
+/// the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use csvrow::CsvWriter;
+/// let mut writer = CsvWriter::new(Vec::new(), ',');
+/// writer.write_field("a").unwrap();
+/// writer.write_field("b,c").unwrap();
+/// writer.end_record().unwrap();
+///
+/// assert_eq!(b"a,\"b,c\"\n", &writer.into_inner()[..])
+/// ```
+#[cfg(feature = "std")]
+pub struct CsvWriter<W> {
+    writer: W,
+    pub delimiter: char,
+    terminator: char,
+    fields: usize,
+    empty: bool,
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> CsvWriter<W> {
+    /// Creates a new CsvWriter
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - A sink to write the records to
+    /// * `delimiter` - A char that represents the delimiter to write between fields
+    pub fn new(writer: W, delimiter: char) -> CsvWriter<W> {
+        CsvWriter {
+            writer,
+            delimiter,
+            terminator: '\n',
+            fields: 0,
+            empty: true,
+        }
+    }
+
+    /// Sets the char that ends each record, instead of a newline
+    ///
+    /// A field containing `terminator` is quoted, as `escape_with_terminator()` would quote it, so that the records
+    /// can be read back with `CsvDocument::terminator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminator` - A char that represents the end of a record
+    pub fn terminator(mut self, terminator: char) -> CsvWriter<W> {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Writes the next field of the current record, preceded by a delimiter unless it is the first
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The value to write
+    pub fn write_field(&mut self, field: &str) -> io::Result<()> {
+        if self.fields > 0 {
+            let mut delimiter = [0; 4];
+            self.writer
+                .write_all(self.delimiter.encode_utf8(&mut delimiter).as_bytes())?;
+        }
+
+        self.writer
+            .write_all(escape_with_terminator(field, self.delimiter, self.terminator).as_bytes())?;
+        self.fields += 1;
+        self.empty &= field.is_empty();
+
+        Ok(())
+    }
+
+    /// Ends the current record with its terminator, so that the next field starts a new record
+    pub fn end_record(&mut self) -> io::Result<()> {
+        if self.fields == 1 && self.empty {
+            self.writer.write_all(b"\"\"")?;
+        }
+
+        self.fields = 0;
+        self.empty = true;
+
+        let mut terminator = [0; 4];
+        self.writer
+            .write_all(self.terminator.encode_utf8(&mut terminator).as_bytes())
+    }
+
+    /// Returns the delimiter, quote, escape style and terminator this CsvWriter writes with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use csvrow::{CsvWriter, Delimiter, EscapeStyle};
+    /// let writer = CsvWriter::new(Vec::new(), '\t').terminator('\r');
+    /// let dialect = writer.dialect();
+    ///
+    /// assert_eq!(Delimiter::Char('\t'), dialect.delimiter);
+    /// assert_eq!(Some('"'), dialect.quote);
+    /// assert_eq!(EscapeStyle::Doubled, dialect.escape_style);
+    /// assert_eq!(Some('\r'), dialect.terminator)
+    /// ```
+    pub fn dialect(&self) -> Dialect<'static> {
+        Dialect {
+            delimiter: Delimiter::Char(self.delimiter),
+            quote: Some('"'),
+            escape_style: EscapeStyle::Doubled,
+            terminator: Some(self.terminator),
+        }
+    }
+
+    /// Flushes the sink
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the sink, without flushing it
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::CsvRow;
+
+    #[test]
+    fn writes_simple_row() {
+        let line = write_row(["january", "february", "march"], ',');
+
+        assert_eq!("january,february,march", line)
+    }
+
+    #[test]
+    fn writes_empty_row() {
+        let line = write_row(Vec::<String>::new(), ',');
+
+        assert_eq!("", line)
+    }
+
+    #[test]
+    fn writes_single_empty_field_quoted() {
+        let line = write_row([""], ',');
+
+        assert_eq!("\"\"", line)
+    }
+
+    #[test]
+    fn escapes_fields_with_delimiter_and_quotes() {
+        let line = write_row(["leap day, the", "The \"Coder\" Man", "a;b"], ',');
+
+        assert_eq!("\"leap day, the\",\"The \"\"Coder\"\" Man\",a;b", line)
+    }
+
+    #[test]
+    fn parse_write_parse_is_stable() {
+        let rows = [
+            "a,b,c,d",
+            r#"january,february,"leap day, the",march,april"#,
+            r#"january,"The ""Coder"" Man",march"#,
+            r#"january, "february", march"#,
+            r#"è,"""quoted""",,"""#,
+            "a;b,c\tdé",
+            r#""""""#,
+        ];
+
+        for row in rows {
+            let parsed: Vec<_> = CsvRow::new(row, ',', false).collect();
+            let written = write_row(&parsed, ',');
+            let reparsed: Vec<_> = CsvRow::new(&written, ',', false).collect();
+
+            assert_eq!(parsed, reparsed, "{row} was rewritten as {written}");
+            assert_eq!(written, write_row(&reparsed, ','));
+        }
+    }
+
+    #[test]
+    fn join_round_trips_through_csv_row() {
+        let fields = [
+            "january",
+            "",
+            "leap day, the",
+            "The \"Coder\" Man",
+            "a\nb",
+            "",
+        ];
+
+        let line = join(fields, ',');
+        let vec_r: Vec<_> = CsvRow::new(&line, ',', false).collect();
+
+        assert_eq!(fields[..], vec_r[..])
+    }
+
+    #[test]
+    fn joins_no_fields_to_empty_line() {
+        assert_eq!("", join(Vec::<&str>::new(), ';'))
+    }
+
+    #[test]
+    fn displays_row_with_embedded_comma_quoted() {
+        let row = Row::new(
+            CsvRow::new(r#"january,"leap day, the","The ""Coder"" Man""#, ',', false).collect(),
+            ',',
+        );
+
+        assert_eq!(
+            r#"january,"leap day, the","The ""Coder"" Man""#,
+            row.to_string()
+        )
+    }
+
+    #[test]
+    fn displays_row_as_write_row_writes_it() {
+        for fields in [vec![], vec![""], vec!["", ""], vec!["a", "b\nc"]] {
+            let row = Row::new(fields.iter().map(|&f| f.into()).collect(), ',');
+
+            assert_eq!(write_row(&fields, ','), row.to_string());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn csv_writer_writes_escaped_records() {
+        let mut writer = CsvWriter::new(Vec::new(), ';');
+
+        for field in ["january", "leap; day", "The \"Coder\" Man"] {
+            writer.write_field(field).unwrap();
+        }
+        writer.end_record().unwrap();
+        writer.write_field("").unwrap();
+        writer.end_record().unwrap();
+
+        assert_eq!(
+            b"january;\"leap; day\";\"The \"\"Coder\"\" Man\"\n\"\"\n",
+            &writer.into_inner()[..]
+        )
+    }
+
+    #[test]
+    fn record_to_line_quotes_fields() {
+        assert_eq!("\"a,b\"", Record::from(vec!["a,b".into()]).to_line(','));
+
+        let record = Record::from(vec!["a,b".to_string(), "c".to_string()]);
+
+        assert_eq!("\"a,b\",c", record.to_line(','));
+        assert_eq!(record, Record::parse(&record.to_line(','), ','));
+        assert_eq!(vec!["a,b", "c"], record.into_iter().collect::<Vec<_>>())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn csv_writer_quotes_fields_holding_its_terminator() {
+        let mut writer = CsvWriter::new(Vec::new(), ',').terminator('\r');
+
+        for field in ["a\rb", "c"] {
+            writer.write_field(field).unwrap();
+        }
+        writer.end_record().unwrap();
+
+        assert_eq!(b"\"a\rb\",c\r", &writer.into_inner()[..])
+    }
+}
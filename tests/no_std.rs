@@ -0,0 +1,36 @@
+//! Exercises the crate built without its `std` feature, as with `cargo test --no-default-features`
+#![cfg(not(feature = "std"))]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use csvrow::{escape, unescape, write_row, CsvDocument, CsvRow};
+
+#[test]
+fn can_parse_row_without_std() {
+    let row = r#"a,"b,""c""",d"#;
+
+    let csv = CsvRow::new(row, ',', false);
+    let vec_t: Vec<_> = alloc::vec!["a", "b,\"c\"", "d"];
+    let vec_r: Vec<_> = csv.collect();
+
+    assert_eq!(vec_t[..], vec_r[..])
+}
+
+#[test]
+fn can_escape_and_write_without_std() {
+    assert_eq!("\"b,c\"", escape("b,c", ','));
+    assert_eq!("b,c", unescape("\"b,c\"", ','));
+    assert_eq!("a,\"b,c\"", write_row(["a", "b,c"], ','));
+}
+
+#[test]
+fn can_parse_document_without_std() {
+    let doc = CsvDocument::new("a,b\nc,d", ',', false);
+
+    let vec_r: Vec<Vec<_>> = doc.collect();
+
+    assert_eq!(2, vec_r.len())
+}